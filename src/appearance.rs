@@ -0,0 +1,84 @@
+use mesh::{EquipmentMesh, EquipmentMeshTable};
+use monster::BaseMonster;
+
+pub struct ResolvedEquipment<'a> {
+	pub look_id: u32,
+	pub color:   u8,
+	pub mesh:    &'a EquipmentMesh
+}
+
+pub struct MonsterAppearance<'a> {
+	pub body:     &'a EquipmentMesh,
+	pub armet:    Option<ResolvedEquipment<'a>>,
+	pub r_weapon: Option<ResolvedEquipment<'a>>,
+	pub l_weapon: Option<ResolvedEquipment<'a>>,
+	pub mount:    Option<ResolvedEquipment<'a>>
+}
+
+pub enum AppearanceIssue {
+	InvalidTypeId(String),
+	MissingBody(u32),
+	MissingArmet(u32),
+	MissingWeapon(u32),
+	MissingMount(u32)
+}
+
+// Resolves the look of monsters against the client Armor, Weapon and Armet tables.
+// The body and the mount are looked up in the armor table, a look id of 0 meaning nothing is worn.
+pub struct AppearanceResolver<'a> {
+	pub armors:  &'a EquipmentMeshTable,
+	pub weapons: &'a EquipmentMeshTable,
+	pub armets:  &'a EquipmentMeshTable
+}
+
+impl<'a> AppearanceResolver<'a> {
+	pub fn new(armors: &'a EquipmentMeshTable, weapons: &'a EquipmentMeshTable, armets: &'a EquipmentMeshTable) -> AppearanceResolver<'a> {
+		AppearanceResolver { armors, weapons, armets }
+	}
+
+	pub fn resolve(&self, monster: &BaseMonster) -> Result<MonsterAppearance<'a>, Vec<AppearanceIssue>> {
+		let mut issues = Vec::new();
+
+		let body = match monster.type_id.parse::<u32>() {
+			Result::Ok(type_id) => {
+				let body = self.armors.find(&type_id);
+
+				if body.is_none() {
+					issues.push(AppearanceIssue::MissingBody(type_id));
+				}
+
+				body
+			},
+
+			Result::Err(_) => {
+				issues.push(AppearanceIssue::InvalidTypeId(monster.type_id.clone()));
+				None
+			}
+		};
+
+		let armet = resolve_part(self.armets, &(monster.armet as u32), &monster.armet_color, AppearanceIssue::MissingArmet, &mut issues);
+		let r_weapon = resolve_part(self.weapons, &(monster.r_weapon as u32), &0, AppearanceIssue::MissingWeapon, &mut issues);
+		let l_weapon = resolve_part(self.weapons, &(monster.l_weapon as u32), &monster.l_weapon_color, AppearanceIssue::MissingWeapon, &mut issues);
+		let mount = resolve_part(self.armors, &(monster.mount as u32), &0, AppearanceIssue::MissingMount, &mut issues);
+
+		match body {
+			Some(body) if issues.is_empty() => Result::Ok(MonsterAppearance { body, armet, r_weapon, l_weapon, mount }),
+			_                               => Result::Err(issues)
+		}
+	}
+}
+
+fn resolve_part<'a, F>(table: &'a EquipmentMeshTable, look_id: &u32, color: &u8, on_missing: F, issues: &mut Vec<AppearanceIssue>) -> Option<ResolvedEquipment<'a>>
+	where F: Fn(u32) -> AppearanceIssue {
+	if *look_id == 0 {
+		return None;
+	}
+
+	match table.find(look_id) {
+		Some(mesh) => Some(ResolvedEquipment { look_id: *look_id, color: *color, mesh }),
+		None => {
+			issues.push(on_missing(*look_id));
+			None
+		}
+	}
+}
\ No newline at end of file
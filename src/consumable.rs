@@ -0,0 +1,92 @@
+use item::BaseItem;
+
+// Consumables are the 10xxxxx item ids, teleport scrolls the 106002x ones.
+pub const CONSUMABLE_ID_PREFIX: u32 = 10;
+pub const TELEPORT_SCROLL_ID_PREFIX: u32 = 106002;
+
+#[derive(Clone, PartialEq)]
+pub enum ConsumableEffect {
+	RestoreLife(u16),
+	RestoreMana(u16),
+	Teleport(u32),
+	TriggerAction(u32)
+}
+
+#[derive(Clone)]
+pub struct CharacterState {
+	pub life:              u32,
+	pub max_life:          u32,
+	pub mana:              u32,
+	pub max_mana:          u32,
+	pub teleport_action:   Option<u32>,
+	pub triggered_actions: Vec<u32>
+}
+
+impl BaseItem {
+	pub fn is_consumable(&self) -> bool { self.id / 100000 == CONSUMABLE_ID_PREFIX }
+
+	pub fn is_teleport_scroll(&self) -> bool { self.id / 10 == TELEPORT_SCROLL_ID_PREFIX }
+
+	// Effects of using the item once, in the order the server applies them.
+	pub fn get_consumable_effects(&self) -> Vec<ConsumableEffect> {
+		let mut effects = Vec::new();
+
+		if !self.is_consumable() {
+			return effects;
+		}
+
+		if self.hp_restored > 0 {
+			effects.push(ConsumableEffect::RestoreLife(self.hp_restored));
+		}
+
+		if self.mp_restored > 0 {
+			effects.push(ConsumableEffect::RestoreMana(self.mp_restored));
+		}
+
+		if self.action_id > 0 {
+			effects.push(if self.is_teleport_scroll() {
+				ConsumableEffect::Teleport(self.action_id)
+			} else {
+				ConsumableEffect::TriggerAction(self.action_id)
+			});
+		}
+
+		effects
+	}
+}
+
+impl ConsumableEffect {
+	pub fn apply_to(&self, state: &CharacterState) -> CharacterState {
+		let mut result = state.clone();
+
+		match *self {
+			ConsumableEffect::RestoreLife(amount) => {
+				result.life = state.life.saturating_add(amount as u32).min(state.max_life);
+			},
+
+			ConsumableEffect::RestoreMana(amount) => {
+				result.mana = state.mana.saturating_add(amount as u32).min(state.max_mana);
+			},
+
+			ConsumableEffect::Teleport(action_id) => {
+				result.teleport_action = Some(action_id);
+			},
+
+			ConsumableEffect::TriggerAction(action_id) => {
+				result.triggered_actions.push(action_id);
+			}
+		}
+
+		result
+	}
+}
+
+pub fn apply_consumable_effects(state: &CharacterState, effects: &[ConsumableEffect]) -> CharacterState {
+	effects
+		.iter()
+		.fold(state.clone(), |current, effect| effect.apply_to(&current))
+}
+
+pub fn use_item(state: &CharacterState, item: &BaseItem) -> CharacterState {
+	apply_consumable_effects(state, &item.get_consumable_effects())
+}
\ No newline at end of file
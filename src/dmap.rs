@@ -0,0 +1,254 @@
+use parser::{BinaryParseable, BinaryParserError, BinaryReader};
+use serializer::{BinarySerializableStructure, BinarySerializer};
+
+pub const DMAP_PUZZLE_PATH_LENGTH: usize = 260;
+
+#[derive(Clone, Copy)]
+pub struct DMapCell {
+	pub mask:    u16,
+	pub surface: u16,
+	pub height:  i16
+}
+
+impl DMapCell {
+	pub fn is_walkable(&self) -> bool { self.mask == 0 }
+}
+
+pub struct DMapPortal {
+	pub x:  u32,
+	pub y:  u32,
+	pub id: u32
+}
+
+// Cell grid and portals of a DMap file. Scene and effect layers following the portals are
+// not interpreted and are written back as they were read. The grid is only reachable
+// through accessors, so that it always holds width * height cells.
+pub struct DMap {
+	pub version:     u32,
+	pub data:        u32,
+	pub puzzle_path: String,
+	width:           u32,
+	height:          u32,
+	cells:           Vec<DMapCell>,
+	pub portals:     Vec<DMapPortal>,
+	pub layers_data: Vec<u8>
+}
+
+impl DMap {
+	// Map of the given size, every cell being walkable and flat.
+	pub fn new(width: u32, height: u32) -> DMap {
+		DMap {
+			version:     0,
+			data:        0,
+			puzzle_path: String::new(),
+			width,
+			height,
+			cells:       vec![DMapCell { mask: 0, surface: 0, height: 0 }; width as usize * height as usize],
+			portals:     Vec::new(),
+			layers_data: Vec::new()
+		}
+	}
+
+	pub fn from_bytes(buffer: &[u8]) -> Result<DMap, BinaryParserError> {
+		let mut reader = BinaryReader::new(buffer);
+
+		DMap::from_reader(&mut reader)
+	}
+
+	pub fn serialize_binary(&self) -> Vec<u8> {
+		let mut serializer = BinarySerializer::new();
+
+		BinarySerializableStructure::serialize_binary(self, &mut serializer);
+
+		serializer.get_buffer()
+	}
+
+	pub fn get_width(&self) -> u32 { self.width }
+
+	pub fn get_height(&self) -> u32 { self.height }
+
+	// Cells row by row.
+	pub fn get_cells(&self) -> &[DMapCell] { &self.cells }
+
+	fn get_index(&self, x: &u32, y: &u32) -> Option<usize> {
+		if *x >= self.width || *y >= self.height {
+			return None;
+		}
+
+		Some((*y * self.width + *x) as usize)
+	}
+
+	pub fn get_cell(&self, x: &u32, y: &u32) -> Option<&DMapCell> {
+		self.get_index(x, y).map(|idx| &self.cells[idx])
+	}
+
+	pub fn set_cell(&mut self, x: &u32, y: &u32, cell: DMapCell) -> bool {
+		match self.get_index(x, y) {
+			Some(idx) => {
+				self.cells[idx] = cell;
+				true
+			},
+			None => false
+		}
+	}
+
+	// Cells out of the map are never walkable.
+	pub fn is_walkable(&self, x: &u32, y: &u32) -> bool {
+		self.get_cell(x, y).map(|cell| cell.is_walkable()).unwrap_or(false)
+	}
+
+	pub fn height(&self, x: &u32, y: &u32) -> Option<i16> {
+		self.get_cell(x, y).map(|cell| cell.height)
+	}
+
+	pub fn surface(&self, x: &u32, y: &u32) -> Option<u16> {
+		self.get_cell(x, y).map(|cell| cell.surface)
+	}
+
+	pub fn get_portal_at(&self, x: &u32, y: &u32) -> Option<&DMapPortal> {
+		self.portals.iter().find(|portal| portal.x == *x && portal.y == *y)
+	}
+
+	// Checksum closing each row of cells, as computed by the client.
+	fn get_row_checksum(&self, y: &u32) -> u32 {
+		let mut checksum: u32 = 0;
+
+		for x in 0..self.width {
+			let cell = &self.cells[(*y * self.width + x) as usize];
+
+			checksum = checksum
+				.wrapping_add((cell.mask as u32).wrapping_mul(cell.surface as u32 + *y + 1))
+				.wrapping_add(((cell.height as i32 + 2) as u32).wrapping_mul(x + 1 + cell.surface as u32));
+		}
+
+		checksum
+	}
+}
+
+mod serializer {
+	use super::{DMap, DMAP_PUZZLE_PATH_LENGTH};
+	use serializer::{BinarySerializableStructure, BinarySerializer};
+
+	impl BinarySerializableStructure for DMap {
+		fn serialize_binary(&self, serializer: &mut BinarySerializer) {
+			serializer.push_u32(&self.version);
+			serializer.push_u32(&self.data);
+			serializer.push_fixed_string(&self.puzzle_path, DMAP_PUZZLE_PATH_LENGTH);
+			serializer.push_u32(&self.width);
+			serializer.push_u32(&self.height);
+
+			for y in 0..self.height {
+				for x in 0..self.width {
+					let cell = &self.cells[(y * self.width + x) as usize];
+
+					serializer.push_u16(&cell.mask);
+					serializer.push_u16(&cell.surface);
+					serializer.push_i16(&cell.height);
+				}
+
+				serializer.push_u32(&self.get_row_checksum(&y));
+			}
+
+			serializer.push_u32(&(self.portals.len() as u32));
+
+			for portal in &self.portals {
+				serializer.push_u32(&portal.x);
+				serializer.push_u32(&portal.y);
+				serializer.push_u32(&portal.id);
+			}
+
+			serializer.push_bytes(&self.layers_data);
+		}
+	}
+}
+
+mod parser {
+	use super::{DMap, DMapCell, DMapPortal, DMAP_PUZZLE_PATH_LENGTH};
+	use parser::{BinaryParseable, BinaryParserError, BinaryReader};
+
+	impl BinaryParseable<DMap> for DMap {
+		fn from_reader(reader: &mut BinaryReader) -> Result<DMap, BinaryParserError> {
+			let version = reader.read_u32()?;
+			let data = reader.read_u32()?;
+			let puzzle_path = reader.read_fixed_string(DMAP_PUZZLE_PATH_LENGTH)?;
+			let width = reader.read_u32()?;
+			let height = reader.read_u32()?;
+
+			let cell_count = match width.checked_mul(height) {
+				Some(cell_count) => cell_count as usize,
+				None => return Result::Err(BinaryParserError::InvalidValue(format!("map size {}x{}", width, height)))
+			};
+
+			let mut cells = Vec::with_capacity(reader.get_bounded_capacity(cell_count, 6));
+
+			for _ in 0..height {
+				for _ in 0..width {
+					cells.push(DMapCell {
+						mask:    reader.read_u16()?,
+						surface: reader.read_u16()?,
+						height:  reader.read_i16()?
+					});
+				}
+
+				// Row checksum, recomputed when writing.
+				reader.skip(4)?;
+			}
+
+			let portal_count = reader.read_u32()? as usize;
+			let mut portals = Vec::new();
+
+			for _ in 0..portal_count {
+				portals.push(DMapPortal {
+					x:  reader.read_u32()?,
+					y:  reader.read_u32()?,
+					id: reader.read_u32()?
+				});
+			}
+
+			let remaining = reader.get_remaining();
+			let layers_data = reader.read_bytes(remaining)?.to_vec();
+
+			Result::Ok(DMap { version, data, puzzle_path, width, height, cells, portals, layers_data })
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{DMap, DMapCell, DMapPortal};
+
+	fn read(buffer: &[u8]) -> DMap {
+		match DMap::from_bytes(buffer) {
+			Result::Ok(dmap) => dmap,
+			Result::Err(_)   => panic!("failed to parse the dmap")
+		}
+	}
+
+	#[test]
+	fn round_trips_with_row_checksums() {
+		let mut dmap = DMap::new(2, 2);
+
+		dmap.puzzle_path = String::from("map/puzzle/test.pux");
+		dmap.set_cell(&0, &0, DMapCell { mask: 1, surface: 2, height: 3 });
+		dmap.set_cell(&1, &1, DMapCell { mask: 0, surface: 4, height: -5 });
+		dmap.portals.push(DMapPortal { x: 1, y: 0, id: 7 });
+		dmap.layers_data = vec![1, 2, 3];
+
+		let buffer = dmap.serialize_binary();
+		let read_back = read(&buffer);
+
+		assert_eq!(read_back.get_width(), 2);
+		assert_eq!(read_back.get_height(), 2);
+		assert_eq!(read_back.puzzle_path, dmap.puzzle_path);
+		assert_eq!(read_back.height(&1, &1), Some(-5));
+		assert!(!read_back.is_walkable(&0, &0));
+		assert_eq!(read_back.get_portal_at(&1, &0).map(|portal| portal.id), Some(7));
+		assert_eq!(read_back.layers_data, vec![1, 2, 3]);
+		assert_eq!(read_back.serialize_binary(), buffer);
+
+		// First row: 1 * (2 + 0 + 1) + (3 + 2) * (0 + 1 + 2) for the first cell,
+		// (0 + 2) * (1 + 1 + 0) for the second one.
+		let checksum_at = 4 + 4 + 260 + 4 + 4 + 2 * 6;
+		assert_eq!(&buffer[checksum_at..checksum_at + 4], &22u32.to_le_bytes());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+use item::BaseItem;
+
+// The client shows durability in hundredths of the stored amount.
+pub const DURABILITY_DISPLAY_RATIO: u16 = 100;
+
+pub enum FrayMode {
+	Never,
+	Wearing {
+		hit_loss:       u16,
+		death_loss_pct: u32
+	}
+}
+
+impl FrayMode {
+	// 0 means the item never wears. Otherwise the three lowest digits hold the durability lost
+	// on each hit, the upper digits the percentage of the max durability lost on death. The
+	// percentage is kept as stored, and only capped to 100 when applied.
+	pub fn from_raw(fray_mode: &u32) -> FrayMode {
+		if *fray_mode == 0 {
+			return FrayMode::Never;
+		}
+
+		FrayMode::Wearing {
+			hit_loss:       (*fray_mode % 1000) as u16,
+			death_loss_pct: *fray_mode / 1000
+		}
+	}
+
+	pub fn get_as_raw(&self) -> u32 {
+		match *self {
+			FrayMode::Never => 0,
+			FrayMode::Wearing { hit_loss, death_loss_pct } => death_loss_pct * 1000 + hit_loss as u32
+		}
+	}
+}
+
+pub enum RepairMode {
+	Silver,
+	Unrepairable,
+	Unknown(u32)
+}
+
+impl RepairMode {
+	pub fn from_raw(repair_mode: &u32) -> RepairMode {
+		match *repair_mode {
+			0     => RepairMode::Silver,
+			1     => RepairMode::Unrepairable,
+			other => RepairMode::Unknown(other)
+		}
+	}
+
+	pub fn get_as_raw(&self) -> u32 {
+		match *self {
+			RepairMode::Silver         => 0,
+			RepairMode::Unrepairable   => 1,
+			RepairMode::Unknown(other) => other
+		}
+	}
+}
+
+impl BaseItem {
+	pub fn get_fray_mode(&self) -> FrayMode { FrayMode::from_raw(&self.fray_mode) }
+
+	pub fn get_repair_mode(&self) -> RepairMode { RepairMode::from_raw(&self.repair_mode) }
+
+	// Tests if the item has a durability at all (potions, arrows and such do not).
+	pub fn has_durability(&self) -> bool { self.amount_limit > 0 }
+
+	// Tests if the given durability leaves the item broken.
+	pub fn is_broken(&self, durability: &u16) -> bool { self.has_durability() && *durability == 0 }
+
+	pub fn get_durability_after_hits(&self, durability: &u16, hits: &u32) -> u16 {
+		match self.get_fray_mode() {
+			FrayMode::Never => *durability,
+			FrayMode::Wearing { hit_loss, .. } => {
+				let loss = (hit_loss as u32).saturating_mul(*hits);
+
+				(*durability as u32).saturating_sub(loss) as u16
+			}
+		}
+	}
+
+	pub fn get_durability_after_death(&self, durability: &u16) -> u16 {
+		match self.get_fray_mode() {
+			FrayMode::Never => *durability,
+			FrayMode::Wearing { death_loss_pct, .. } => {
+				let loss = self.amount_limit as u32 * death_loss_pct.min(100) / 100;
+
+				(*durability as u32).saturating_sub(loss) as u16
+			}
+		}
+	}
+
+	// Silver needed to bring the item back to its max durability, or None if it cannot be repaired.
+	// Repairing costs half the buy price when the item is broken, proportionally less otherwise.
+	pub fn get_repair_cost(&self, durability: &u16) -> Option<u32> {
+		if let RepairMode::Silver = self.get_repair_mode() {
+			if !self.has_durability() || *durability >= self.amount_limit {
+				return Some(0);
+			}
+
+			let lost = (self.amount_limit - *durability) as u64;
+			let cost = self.buy_price as u64 * lost / self.amount_limit as u64 / 2;
+
+			return Some(cost.max(1) as u32);
+		}
+
+		None
+	}
+}
+
+// Durability as shown on the client tooltip, rounded up.
+pub fn get_displayed_durability(durability: &u16) -> u16 {
+	(*durability as u32).div_ceil(DURABILITY_DISPLAY_RATIO as u32) as u16
+}
\ No newline at end of file
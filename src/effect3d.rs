@@ -0,0 +1,143 @@
+use magictypetable::MagicTypeTable;
+use monstertable::MonsterTable;
+use parser::{parse_ini_file, IniEntryParserError};
+use reference::{is_reference_set, ReferenceSource};
+use serializer::serialize_ini_file;
+
+pub struct EffectPart {
+	pub c3_id:      u32,
+	pub texture_id: u32,
+	pub asb:        u8,
+	pub adb:        u8
+}
+
+// Effect from the client 3DEffect.ini, keyed by its name.
+pub struct Effect3D {
+	pub name:           String,
+	pub parts:          Vec<EffectPart>,
+	pub delay:          u32,
+	pub loop_time:      u32,
+	pub frame_interval: u32
+}
+
+pub struct MissingEffect {
+	pub source:      ReferenceSource,
+	pub field:       &'static str,
+	pub effect_name: String
+}
+
+pub struct Effect3DTable {
+	pub effects: Vec<Effect3D>
+}
+
+impl Effect3DTable {
+	pub fn from_file_string(content: &str) -> Result<Effect3DTable, IniEntryParserError> {
+		Result::Ok(Effect3DTable {
+			effects: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_ini_file(&self.effects)
+	}
+
+	pub fn find(&self, name: &str) -> Option<&Effect3D> {
+		self.effects.iter().find(|effect| effect.name == name)
+	}
+
+	// Reports every effect name referenced by magictype rows or monsters that the table lacks.
+	pub fn get_missing_effects(&self, magic_types: &MagicTypeTable, monsters: &MonsterTable) -> Vec<MissingEffect> {
+		let mut missing = Vec::new();
+
+		for entry in &magic_types.entries {
+			let references = [
+				("intone_effect", &entry.effects.intone_effect),
+				("sender_effect", &entry.effects.sender_effect),
+				("target_effect", &entry.effects.target_effect),
+				("ground_effect", &entry.effects.ground_effect),
+				("trace_effect", &entry.effects.trace_effect)
+			];
+
+			for &(field, effect_name) in references.iter() {
+				if is_reference_set(effect_name) && self.find(effect_name).is_none() {
+					missing.push(MissingEffect {
+						source:      ReferenceSource::MagicType(entry.id, entry.skill_lvl),
+						field,
+						effect_name: effect_name.clone()
+					});
+				}
+			}
+		}
+
+		for monster in &monsters.monsters {
+			if is_reference_set(&monster.born_effect) && self.find(&monster.born_effect).is_none() {
+				missing.push(MissingEffect {
+					source:      ReferenceSource::Monster(monster.name.clone()),
+					field:       "born_effect",
+					effect_name: monster.born_effect.clone()
+				});
+			}
+		}
+
+		missing
+	}
+}
+
+mod serializer {
+	use super::Effect3D;
+	use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for Effect3D {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.name);
+			serializer.add_entry("Amount", &self.parts.len());
+
+			for (idx, part) in self.parts.iter().enumerate() {
+				serializer.add_entry(&format!("EffectId{}", idx), &part.c3_id);
+				serializer.add_entry(&format!("TextureId{}", idx), &part.texture_id);
+				serializer.add_entry(&format!("ASB{}", idx), &part.asb);
+				serializer.add_entry(&format!("ADB{}", idx), &part.adb);
+			}
+
+			serializer.add_entry("Delay", &self.delay);
+			serializer.add_entry("LoopTime", &self.loop_time);
+			serializer.add_entry("FrameInterval", &self.frame_interval);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::{Effect3D, EffectPart};
+	use parser::{IniEntryParser, IniEntryParserError, IniEntryParseable};
+
+	impl IniEntryParseable<Effect3D> for Effect3D {
+		fn from_section_string(section: &String) -> Result<Effect3D, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+			let part_count: usize = parser.get_entry_as("Amount")?;
+			let mut parts = Vec::new();
+
+			for idx in 0..part_count {
+				parts.push(EffectPart {
+					c3_id:      parser.get_entry_as(&format!("EffectId{}", idx))?,
+					texture_id: parser.get_entry_as(&format!("TextureId{}", idx))?,
+					asb:        parser.get_entry_as_or(&format!("ASB{}", idx), 5)?,
+					adb:        parser.get_entry_as_or(&format!("ADB{}", idx), 2)?
+				});
+			}
+
+			let effect = Effect3D {
+				name:           parser.get_section(),
+				parts,
+				delay:          parser.get_entry_as_or("Delay", 0)?,
+				loop_time:      parser.get_entry_as_or("LoopTime", 1)?,
+				frame_interval: parser.get_entry_as_or("FrameInterval", 33)?
+			};
+
+			Result::Ok(effect)
+		}
+	}
+}
\ No newline at end of file
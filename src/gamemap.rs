@@ -0,0 +1,119 @@
+use generator::MonsterGeneratorTable;
+use npc::NpcTable;
+use parser::{BinaryParseable, BinaryParserError, BinaryReader};
+use serializer::{BinarySerializableStructure, BinarySerializer};
+
+// Entry of GameMap.dat, linking a map id to its DMap file.
+pub struct GameMapEntry {
+	pub map_id:      u32,
+	pub dmap_path:   String,
+	pub puzzle_size: u32
+}
+
+pub struct GameMapTable {
+	pub maps: Vec<GameMapEntry>
+}
+
+impl GameMapTable {
+	// The file starts with the amount of maps, followed by the entries.
+	pub fn from_bytes(buffer: &[u8]) -> Result<GameMapTable, BinaryParserError> {
+		let mut reader = BinaryReader::new(buffer);
+		let amount = reader.read_u32()? as usize;
+		let mut maps = Vec::with_capacity(reader.get_bounded_capacity(amount, 12));
+
+		for _ in 0..amount {
+			maps.push(GameMapEntry::from_reader(&mut reader)?);
+		}
+
+		Result::Ok(GameMapTable { maps })
+	}
+
+	pub fn serialize_binary(&self) -> Vec<u8> {
+		let mut serializer = BinarySerializer::new();
+
+		serializer.push_u32(&(self.maps.len() as u32));
+
+		for map in &self.maps {
+			map.serialize_binary(&mut serializer);
+		}
+
+		serializer.get_buffer()
+	}
+
+	pub fn find(&self, map_id: &u32) -> Option<&GameMapEntry> {
+		self.maps.iter().find(|map| map.map_id == *map_id)
+	}
+
+	pub fn contains(&self, map_id: &u32) -> bool { self.find(map_id).is_some() }
+
+	pub fn get_dmap_path(&self, map_id: &u32) -> Option<&str> {
+		self.find(map_id).map(|map| map.dmap_path.as_str())
+	}
+
+	// Generator ids spawning on a map missing from the index.
+	pub fn get_generators_on_unknown_maps(&self, generators: &MonsterGeneratorTable) -> Vec<u32> {
+		generators.generators
+			.iter()
+			.filter(|generator| !self.contains(&generator.map_id))
+			.map(|generator| generator.id)
+			.collect()
+	}
+
+	// Npc ids standing on a map missing from the index.
+	pub fn get_npcs_on_unknown_maps(&self, npcs: &NpcTable) -> Vec<u32> {
+		npcs.npcs
+			.iter()
+			.filter(|npc| !self.contains(&npc.map_id))
+			.map(|npc| npc.id)
+			.collect()
+	}
+}
+
+mod serializer {
+	use super::GameMapEntry;
+	use serializer::{BinarySerializableStructure, BinarySerializer};
+
+	impl BinarySerializableStructure for GameMapEntry {
+		fn serialize_binary(&self, serializer: &mut BinarySerializer) {
+			serializer.push_u32(&self.map_id);
+			serializer.push_sized_string(&self.dmap_path);
+			serializer.push_u32(&self.puzzle_size);
+		}
+	}
+}
+
+mod parser {
+	use super::GameMapEntry;
+	use parser::{BinaryParseable, BinaryParserError, BinaryReader};
+
+	impl BinaryParseable<GameMapEntry> for GameMapEntry {
+		fn from_reader(reader: &mut BinaryReader) -> Result<GameMapEntry, BinaryParserError> {
+			Result::Ok(GameMapEntry {
+				map_id:      reader.read_u32()?,
+				dmap_path:   reader.read_sized_string()?,
+				puzzle_size: reader.read_u32()?
+			})
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::GameMapTable;
+	use parser::BinaryParserError;
+
+	fn is_unexpected_eof(buffer: &[u8]) -> bool {
+		matches!(GameMapTable::from_bytes(buffer), Result::Err(BinaryParserError::UnexpectedEof(_)))
+	}
+
+	#[test]
+	fn fails_on_an_amount_larger_than_the_file() {
+		assert!(is_unexpected_eof(&[0xff, 0xff, 0xff, 0x7f]));
+	}
+
+	#[test]
+	fn fails_on_a_truncated_entry() {
+		// One map with id 1001 whose 16 bytes long path is cut short.
+		assert!(is_unexpected_eof(&[1, 0, 0, 0, 0xe9, 0x03, 0, 0, 16, 0, 0, 0, b'm', b'a', b'p']));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,235 @@
+use item::{BaseItem, ItemCombatStats};
+
+pub const GEM_NO_SOCKET: u8 = 0;
+pub const GEM_EMPTY_SOCKET: u8 = 255;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GemKind {
+	Phoenix,
+	Dragon,
+	Fury,
+	Rainbow,
+	Kylin,
+	Violet,
+	Moon,
+	Tortoise,
+	Thunder,
+	Glory
+}
+
+impl GemKind {
+	pub const ALL: [GemKind; 10] = [
+		GemKind::Phoenix, GemKind::Dragon, GemKind::Fury, GemKind::Rainbow, GemKind::Kylin,
+		GemKind::Violet, GemKind::Moon, GemKind::Tortoise, GemKind::Thunder, GemKind::Glory
+	];
+
+	fn from_tens(tens: &u8) -> Option<GemKind> {
+		match *tens {
+			0  => Some(GemKind::Phoenix),
+			1  => Some(GemKind::Dragon),
+			2  => Some(GemKind::Fury),
+			3  => Some(GemKind::Rainbow),
+			4  => Some(GemKind::Kylin),
+			5  => Some(GemKind::Violet),
+			6  => Some(GemKind::Moon),
+			7  => Some(GemKind::Tortoise),
+			10 => Some(GemKind::Thunder),
+			12 => Some(GemKind::Glory),
+			_  => None
+		}
+	}
+
+	fn get_tens(&self) -> u8 {
+		match *self {
+			GemKind::Phoenix  => 0,
+			GemKind::Dragon   => 1,
+			GemKind::Fury     => 2,
+			GemKind::Rainbow  => 3,
+			GemKind::Kylin    => 4,
+			GemKind::Violet   => 5,
+			GemKind::Moon     => 6,
+			GemKind::Tortoise => 7,
+			GemKind::Thunder  => 10,
+			GemKind::Glory    => 12
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GemGrade {
+	Normal,
+	Refined,
+	Super
+}
+
+impl GemGrade {
+	fn from_unit(unit: &u8) -> Option<GemGrade> {
+		match *unit {
+			1 => Some(GemGrade::Normal),
+			2 => Some(GemGrade::Refined),
+			3 => Some(GemGrade::Super),
+			_ => None
+		}
+	}
+
+	fn get_unit(&self) -> u8 {
+		match *self {
+			GemGrade::Normal  => 1,
+			GemGrade::Refined => 2,
+			GemGrade::Super   => 3
+		}
+	}
+
+	// Picks the value matching the grade.
+	fn pick(&self, normal: u16, refined: u16, superior: u16) -> u16 {
+		match *self {
+			GemGrade::Normal  => normal,
+			GemGrade::Refined => refined,
+			GemGrade::Super   => superior
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Gem {
+	NoSocket,
+	EmptySocket,
+	Socketed(GemKind, GemGrade),
+	Unknown(u8)
+}
+
+impl Gem {
+	// Stored gems are encoded as kind * 10 + grade, grade going from 1 (normal) to 3 (super).
+	pub fn from_byte(value: &u8) -> Gem {
+		match *value {
+			GEM_NO_SOCKET    => Gem::NoSocket,
+			GEM_EMPTY_SOCKET => Gem::EmptySocket,
+			other => {
+				match (GemKind::from_tens(&(other / 10)), GemGrade::from_unit(&(other % 10))) {
+					(Some(kind), Some(grade)) => Gem::Socketed(kind, grade),
+					_                         => Gem::Unknown(other)
+				}
+			}
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		match *self {
+			Gem::NoSocket               => GEM_NO_SOCKET,
+			Gem::EmptySocket            => GEM_EMPTY_SOCKET,
+			Gem::Socketed(kind, grade)  => kind.get_tens() * 10 + grade.get_unit(),
+			Gem::Unknown(other)         => other
+		}
+	}
+
+	// Tests if the slot is opened, whether a gem is set in it or not.
+	pub fn has_socket(&self) -> bool {
+		*self != Gem::NoSocket
+	}
+
+	pub fn get_bonus(&self) -> GemBonus {
+		let mut bonus = GemBonus::none();
+
+		if let Gem::Socketed(kind, grade) = *self {
+			match kind {
+				GemKind::Phoenix  => bonus.magic_atk_pct = grade.pick(5, 10, 15),
+				GemKind::Dragon   => bonus.phys_atk_pct = grade.pick(5, 10, 15),
+				GemKind::Fury     => bonus.critical_pct = grade.pick(5, 10, 15),
+				GemKind::Rainbow  => bonus.xp_pct = grade.pick(10, 15, 25),
+				GemKind::Kylin    => bonus.durability_pct = grade.pick(50, 100, 200),
+				GemKind::Violet   => bonus.weapon_xp_pct = grade.pick(30, 50, 100),
+				GemKind::Moon     => bonus.magic_xp_pct = grade.pick(15, 30, 50),
+				GemKind::Tortoise => bonus.damage_reduction_pct = grade.pick(2, 4, 6),
+				GemKind::Thunder  => bonus.atk = grade.pick(100, 300, 500),
+				GemKind::Glory    => bonus.def = grade.pick(100, 300, 500)
+			}
+		}
+
+		bonus
+	}
+}
+
+pub struct GemBonus {
+	pub phys_atk_pct:         u16,
+	pub magic_atk_pct:        u16,
+	pub critical_pct:         u16,
+	pub xp_pct:               u16,
+	pub durability_pct:       u16,
+	pub weapon_xp_pct:        u16,
+	pub magic_xp_pct:         u16,
+	pub damage_reduction_pct: u16,
+	pub atk:                  u16,
+	pub def:                  u16
+}
+
+impl GemBonus {
+	pub fn none() -> GemBonus {
+		GemBonus {
+			phys_atk_pct:         0,
+			magic_atk_pct:        0,
+			critical_pct:         0,
+			xp_pct:               0,
+			durability_pct:       0,
+			weapon_xp_pct:        0,
+			magic_xp_pct:         0,
+			damage_reduction_pct: 0,
+			atk:                  0,
+			def:                  0
+		}
+	}
+
+	pub fn combine(&self, other: &GemBonus) -> GemBonus {
+		GemBonus {
+			phys_atk_pct:         self.phys_atk_pct + other.phys_atk_pct,
+			magic_atk_pct:        self.magic_atk_pct + other.magic_atk_pct,
+			critical_pct:         self.critical_pct + other.critical_pct,
+			xp_pct:               self.xp_pct + other.xp_pct,
+			durability_pct:       self.durability_pct + other.durability_pct,
+			weapon_xp_pct:        self.weapon_xp_pct + other.weapon_xp_pct,
+			magic_xp_pct:         self.magic_xp_pct + other.magic_xp_pct,
+			damage_reduction_pct: self.damage_reduction_pct + other.damage_reduction_pct,
+			atk:                  self.atk.saturating_add(other.atk),
+			def:                  self.def.saturating_add(other.def)
+		}
+	}
+
+	// Applies the attack and defense parts of the bonus; the others only matter to the server.
+	pub fn apply_to(&self, stats: &ItemCombatStats) -> ItemCombatStats {
+		ItemCombatStats {
+			max_phys_atk: add_pct(&stats.max_phys_atk, &self.phys_atk_pct).saturating_add(self.atk),
+			min_phys_atk: add_pct(&stats.min_phys_atk, &self.phys_atk_pct).saturating_add(self.atk),
+			phys_def:     stats.phys_def.saturating_add(self.def),
+			accuracy:     stats.accuracy,
+			dodge:        stats.dodge,
+			magic_atk:    add_pct(&stats.magic_atk, &self.magic_atk_pct),
+			magic_def:    stats.magic_def,
+			atk_range:    stats.atk_range,
+			atk_speed:    stats.atk_speed
+		}
+	}
+}
+
+fn add_pct(value: &u16, pct: &u16) -> u16 {
+	let result = *value as u32 + *value as u32 * *pct as u32 / 100;
+
+	if result > u16::MAX as u32 { u16::MAX } else { result as u16 }
+}
+
+impl BaseItem {
+	pub fn get_gem1(&self) -> Gem { Gem::from_byte(&self.gem1) }
+
+	pub fn get_gem2(&self) -> Gem { Gem::from_byte(&self.gem2) }
+
+	pub fn set_gem1(&mut self, gem: &Gem) { self.gem1 = gem.get_as_byte(); }
+
+	pub fn set_gem2(&mut self, gem: &Gem) { self.gem2 = gem.get_as_byte(); }
+
+	pub fn get_gems_bonus(&self) -> GemBonus {
+		self.get_gem1().get_bonus().combine(&self.get_gem2().get_bonus())
+	}
+
+	// Combat stats of the item once its socketed gems are taken into account.
+	pub fn get_combat_stats_with_gems(&self) -> ItemCombatStats {
+		self.get_gems_bonus().apply_to(&self.combat_stats)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,180 @@
+use monster::BaseMonster;
+use monstertable::MonsterTable;
+use parser::{parse_ini_file, parse_space_separated_file, IniEntryParserError, SpaceSeparatedParserError};
+use serializer::{serialize_ini_file, serialize_space_separated_file};
+
+// Spawn zone of a monster type, as a rectangle on a map.
+pub struct MonsterGenerator {
+	pub id:           u32,
+	pub map_id:       u32,
+	pub x:            u16,
+	pub y:            u16,
+	pub width:        u16,
+	pub height:       u16,
+	pub max_count:    u16,
+	pub respawn_secs: u32,
+	pub max_per_gen:  u16,
+	pub monster_type: u32
+}
+
+impl MonsterGenerator {
+	// Tests if the given cell is inside the spawn zone.
+	pub fn contains(&self, x: &u16, y: &u16) -> bool {
+		*x >= self.x && (*x as u32) < self.x as u32 + self.width as u32 &&
+		*y >= self.y && (*y as u32) < self.y as u32 + self.height as u32
+	}
+}
+
+pub struct ResolvedGenerator<'a> {
+	pub generator: &'a MonsterGenerator,
+	pub monster:   Option<&'a BaseMonster>
+}
+
+pub struct MonsterGeneratorTable {
+	pub generators: Vec<MonsterGenerator>
+}
+
+impl MonsterGeneratorTable {
+	pub fn from_space_separated_string(content: &str) -> Result<MonsterGeneratorTable, SpaceSeparatedParserError> {
+		Result::Ok(MonsterGeneratorTable {
+			generators: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn from_ini_string(content: &str) -> Result<MonsterGeneratorTable, IniEntryParserError> {
+		Result::Ok(MonsterGeneratorTable {
+			generators: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize_space_separated(&self) -> String {
+		let entries: Vec<SpaceSeparatedGenerator> = self.generators.iter().map(SpaceSeparatedGenerator).collect();
+
+		serialize_space_separated_file(&entries)
+	}
+
+	pub fn serialize_ini(&self) -> String {
+		serialize_ini_file(&self.generators)
+	}
+
+	pub fn get_on_map(&self, map_id: &u32) -> Vec<&MonsterGenerator> {
+		self.generators.iter().filter(|generator| generator.map_id == *map_id).collect()
+	}
+
+	// Joins every generator to the monster it spawns, matched on the monster type id.
+	pub fn resolve<'a>(&'a self, monsters: &'a MonsterTable) -> Vec<ResolvedGenerator<'a>> {
+		self.generators
+			.iter()
+			.map(|generator| ResolvedGenerator {
+				generator,
+				monster: monsters.find_by_type_id(&generator.monster_type)
+			})
+			.collect()
+	}
+
+	// Generators spawning a monster type missing from the monster table.
+	pub fn get_unresolved<'a>(&'a self, monsters: &'a MonsterTable) -> Vec<&'a MonsterGenerator> {
+		self.resolve(monsters)
+			.into_iter()
+			.filter(|resolved| resolved.monster.is_none())
+			.map(|resolved| resolved.generator)
+			.collect()
+	}
+}
+
+struct SpaceSeparatedGenerator<'a>(&'a MonsterGenerator);
+
+mod serializer {
+	use super::{MonsterGenerator, SpaceSeparatedGenerator};
+	use serializer::{IniEntrySerializer, SpaceSeparatedSerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for MonsterGenerator {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.id);
+			serializer.add_entry("MapID", &self.map_id);
+			serializer.add_entry("BoundX", &self.x);
+			serializer.add_entry("BoundY", &self.y);
+			serializer.add_entry("BoundCX", &self.width);
+			serializer.add_entry("BoundCY", &self.height);
+			serializer.add_entry("MaxNpc", &self.max_count);
+			serializer.add_entry("RestSecs", &self.respawn_secs);
+			serializer.add_entry("MaxPerGen", &self.max_per_gen);
+			serializer.add_entry("NpcType", &self.monster_type);
+
+			serializer.get_buffer()
+		}
+	}
+
+	impl<'a> StringSerializableStructure for SpaceSeparatedGenerator<'a> {
+		fn serialize(&self) -> String {
+			let generator = self.0;
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&generator.id);
+			serializer.push_value(&generator.map_id);
+			serializer.push_value(&generator.x);
+			serializer.push_value(&generator.y);
+			serializer.push_value(&generator.width);
+			serializer.push_value(&generator.height);
+			serializer.push_value(&generator.max_count);
+			serializer.push_value(&generator.respawn_secs);
+			serializer.push_value(&generator.max_per_gen);
+			serializer.push_value(&generator.monster_type);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::MonsterGenerator;
+	use parser::*;
+
+	impl SpaceSeparatedParseable<MonsterGenerator> for MonsterGenerator {
+		fn from_line(line: &String) -> Result<MonsterGenerator, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 10 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let generator = MonsterGenerator {
+				id:           get_value_as!(results, 0, u32, "id"),
+				map_id:       get_value_as!(results, 1, u32, "map id"),
+				x:            get_value_as!(results, 2, u16, "bound x"),
+				y:            get_value_as!(results, 3, u16, "bound y"),
+				width:        get_value_as!(results, 4, u16, "bound cx"),
+				height:       get_value_as!(results, 5, u16, "bound cy"),
+				max_count:    get_value_as!(results, 6, u16, "max npc"),
+				respawn_secs: get_value_as!(results, 7, u32, "rest secs"),
+				max_per_gen:  get_value_as!(results, 8, u16, "max per gen"),
+				monster_type: get_value_as!(results, 9, u32, "npc type")
+			};
+
+			Result::Ok(generator)
+		}
+	}
+
+	impl IniEntryParseable<MonsterGenerator> for MonsterGenerator {
+		fn from_section_string(section: &String) -> Result<MonsterGenerator, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+
+			let generator = MonsterGenerator {
+				id:           parser.get_section_id()?,
+				map_id:       parser.get_entry_as("MapID")?,
+				x:            parser.get_entry_as("BoundX")?,
+				y:            parser.get_entry_as("BoundY")?,
+				width:        parser.get_entry_as("BoundCX")?,
+				height:       parser.get_entry_as("BoundCY")?,
+				max_count:    parser.get_entry_as("MaxNpc")?,
+				respawn_secs: parser.get_entry_as("RestSecs")?,
+				max_per_gen:  parser.get_entry_as("MaxPerGen")?,
+				monster_type: parser.get_entry_as("NpcType")?
+			};
+
+			Result::Ok(generator)
+		}
+	}
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+#[derive(Clone)]
 pub struct BaseItem {
 	pub id:              u32,
 	pub name:            String,
@@ -26,6 +27,58 @@ pub struct BaseItem {
 	pub unknown_1:       u8
 }
 
+impl BaseItem {
+	pub fn get_quality(&self) -> ItemQuality { ItemQuality::from_item_id(&self.id) }
+
+	pub fn get_type_mask(&self) -> ItemTypeMask { ItemTypeMask::from_raw(&self.type_mask) }
+
+	pub fn set_type_mask(&mut self, mask: &ItemTypeMask) { self.type_mask = mask.get_as_raw(); }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ItemQuality {
+	Normal,
+	Refined,
+	Unique,
+	Elite,
+	Super
+}
+
+impl ItemQuality {
+	// The quality is held by the last digit of the item id.
+	pub fn from_item_id(id: &u32) -> ItemQuality {
+		match *id % 10 {
+			6 => ItemQuality::Refined,
+			7 => ItemQuality::Unique,
+			8 => ItemQuality::Elite,
+			9 => ItemQuality::Super,
+			_ => ItemQuality::Normal
+		}
+	}
+
+	// Id of the same item with this quality. Normal quality spans several digits, so an id
+	// already of normal quality is kept as is.
+	pub fn apply_to_item_id(&self, id: &u32) -> u32 {
+		let digit = match *self {
+			ItemQuality::Normal if ItemQuality::from_item_id(id) == ItemQuality::Normal => return *id,
+			ItemQuality::Normal  => 0,
+			ItemQuality::Refined => 6,
+			ItemQuality::Unique  => 7,
+			ItemQuality::Elite   => 8,
+			ItemQuality::Super   => 9
+		};
+
+		*id - *id % 10 + digit
+	}
+}
+
+// Tests if the id belongs to an equipment (headgear, necklace, armor, ring, boots, weapon
+// or shield), the only items having a quality, a plus level and sockets.
+pub fn is_equipment_id(id: &u32) -> bool {
+	matches!(*id / 10000, 11..=16 | 40..=59 | 90)
+}
+
+#[derive(Clone)]
 pub struct ItemFlags {
 	pub sell_disabled:       bool,
 	pub no_drop_on_death:    bool,
@@ -60,6 +113,43 @@ impl ItemFlags {
 	}
 }
 
+pub struct ItemTypeMask {
+	pub is_stackable:    bool,
+	pub is_auctionable:  bool,
+	pub is_bound:        bool,
+	pub is_quest_item:   bool,
+	pub is_expiring:     bool,
+	pub unknown_bits:    u32
+}
+
+impl ItemTypeMask {
+	pub const KNOWN_BITS: u32 = 0x1F;
+
+	pub fn from_raw(mask: &u32) -> ItemTypeMask {
+		ItemTypeMask {
+			is_stackable:    (mask & 0x01) > 0,
+			is_auctionable:  (mask & 0x02) > 0,
+			is_bound:        (mask & 0x04) > 0,
+			is_quest_item:   (mask & 0x08) > 0,
+			is_expiring:     (mask & 0x10) > 0,
+			unknown_bits:    mask & !ItemTypeMask::KNOWN_BITS
+		}
+	}
+
+	// Unknown bits are given back untouched so that rewritten files keep them.
+	pub fn get_as_raw(&self) -> u32 {
+		let mut mask: u32 = self.unknown_bits & !ItemTypeMask::KNOWN_BITS;
+		mask |= if self.is_stackable { 0x01 } else { 0x00 };
+		mask |= if self.is_auctionable { 0x02 } else { 0x00 };
+		mask |= if self.is_bound { 0x04 } else { 0x00 };
+		mask |= if self.is_quest_item { 0x08 } else { 0x00 };
+		mask |= if self.is_expiring { 0x10 } else { 0x00 };
+
+		mask
+	}
+}
+
+#[derive(Clone)]
 pub struct ItemRequirements {
 	pub profession:      u8,
 	pub proficiency_lvl: u8,
@@ -94,6 +184,7 @@ impl ItemRequirements {
 	pub fn is_spi_ok(&self, spi: &u16) -> bool { *spi >= self.spi }
 }
 
+#[derive(Clone)]
 pub struct ItemCombatStats {
 	pub max_phys_atk:    u16,
 	pub min_phys_atk:    u16,
@@ -168,7 +259,7 @@ mod parser {
 		fn from_line(line: &String) -> Result<BaseItem, SpaceSeparatedParserError> {
 			let results = get_space_separated_values(line);
 
-			if results.len() < 38 {
+			if results.len() < 40 {
 				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
 			}
 
@@ -177,55 +268,95 @@ mod parser {
 				name:          results[1].get_value().to_owned(),
 
 				requirements:  ItemRequirements {
-					profession:      get_value_as!(results, 1, u8, "profession requirement"),
-					proficiency_lvl: get_value_as!(results, 2, u8, "proficiency level requirement"),
-					lvl:             get_value_as!(results, 3, u8, "level requirement"),
-					sex:             get_value_as!(results, 4, u8, "sex requirement"),
-					str:             get_value_as!(results, 5, u16, "strength requirement"),
-					agi:             get_value_as!(results, 6, u16, "agility requirement"),
-					vit:             get_value_as!(results, 7, u16, "vitality requirement"),
-					spi:             get_value_as!(results, 8, u16, "spirit requirement")
+					profession:      get_value_as!(results, 2, u8, "profession requirement"),
+					proficiency_lvl: get_value_as!(results, 3, u8, "proficiency level requirement"),
+					lvl:             get_value_as!(results, 4, u8, "level requirement"),
+					sex:             get_value_as!(results, 5, u8, "sex requirement"),
+					str:             get_value_as!(results, 6, u16, "strength requirement"),
+					agi:             get_value_as!(results, 7, u16, "agility requirement"),
+					vit:             get_value_as!(results, 8, u16, "vitality requirement"),
+					spi:             get_value_as!(results, 9, u16, "spirit requirement")
 				},
 
-				flags:         ItemFlags::from_byte(&get_value_as!(results, 9, u8, "item flags")),
+				flags:         ItemFlags::from_byte(&get_value_as!(results, 10, u8, "item flags")),
 
-				weight:        get_value_as!(results, 10, u16, "weight"),
-				buy_price:     get_value_as!(results, 11, u32, "buy price (silver)"),
-				action_id:     get_value_as!(results, 12, u32, "action id"),
+				weight:        get_value_as!(results, 11, u16, "weight"),
+				buy_price:     get_value_as!(results, 12, u32, "buy price (silver)"),
+				action_id:     get_value_as!(results, 13, u32, "action id"),
 
 				combat_stats:  ItemCombatStats {
-					max_phys_atk:    get_value_as!(results, 13, u16, "max phys attack"),
-					min_phys_atk:    get_value_as!(results, 14, u16, "min phys attack"),
-					phys_def:        get_value_as!(results, 15, u16, "physical defense"),
-					accuracy:        get_value_as!(results, 16, u8, "accuracy"),
-					dodge:           get_value_as!(results, 17, u8, "dodge"),
-					magic_atk:       get_value_as!(results, 28, u16, "magic atk"),
-					magic_def:       get_value_as!(results, 29, u16, "magic def"),
-					atk_range:       get_value_as!(results, 30, u8, "attack range"),
-					atk_speed:       get_value_as!(results, 31, u16, "attack speed")
+					max_phys_atk:    get_value_as!(results, 14, u16, "max phys attack"),
+					min_phys_atk:    get_value_as!(results, 15, u16, "min phys attack"),
+					phys_def:        get_value_as!(results, 16, u16, "physical defense"),
+					accuracy:        get_value_as!(results, 17, u8, "accuracy"),
+					dodge:           get_value_as!(results, 18, u8, "dodge"),
+					magic_atk:       get_value_as!(results, 29, u16, "magic atk"),
+					magic_def:       get_value_as!(results, 30, u16, "magic def"),
+					atk_range:       get_value_as!(results, 31, u8, "attack range"),
+					atk_speed:       get_value_as!(results, 32, u16, "attack speed")
 				},
 
-				hp_restored:   get_value_as!(results, 18, u16, "hp restored"),
-				mp_restored:   get_value_as!(results, 19, u16, "mp restored"),
-				amount:        get_value_as!(results, 20, u16, "amount"),
-				amount_limit:  get_value_as!(results, 21, u16, "amount limit"),
-				status:        get_value_as!(results, 22, u16, "status"),
-				gem1:          get_value_as!(results, 23, u8, "gem 1"),
-				gem2:          get_value_as!(results, 24, u8, "gem 2"),
-				magic1:        get_value_as!(results, 25, u8, "magic 1"),
-				magic2:        get_value_as!(results, 26, u8, "magic 2"),
-				magic3:        get_value_as!(results, 27, u8, "magic 3"),
-
-				fray_mode:     get_value_as!(results, 32, u32, "fray mode"),
-				repair_mode:   get_value_as!(results, 33, u32, "repair mode"),
-				type_mask:     get_value_as!(results, 34, u32, "type mask"),
-				buy_cps_price: get_value_as!(results, 35, u16, "buy price (cps)"),
-				type_name:     results[36].get_value().to_owned(),
-				description:   results[37].get_value().to_owned(),
-				unknown_1:     get_value_as!(results, 38, u8, "unknown 1")
+				hp_restored:   get_value_as!(results, 19, u16, "hp restored"),
+				mp_restored:   get_value_as!(results, 20, u16, "mp restored"),
+				amount:        get_value_as!(results, 21, u16, "amount"),
+				amount_limit:  get_value_as!(results, 22, u16, "amount limit"),
+				status:        get_value_as!(results, 23, u16, "status"),
+				gem1:          get_value_as!(results, 24, u8, "gem 1"),
+				gem2:          get_value_as!(results, 25, u8, "gem 2"),
+				magic1:        get_value_as!(results, 26, u8, "magic 1"),
+				magic2:        get_value_as!(results, 27, u8, "magic 2"),
+				magic3:        get_value_as!(results, 28, u8, "magic 3"),
+
+				fray_mode:     get_value_as!(results, 33, u32, "fray mode"),
+				repair_mode:   get_value_as!(results, 34, u32, "repair mode"),
+				type_mask:     get_value_as!(results, 35, u32, "type mask"),
+				buy_cps_price: get_value_as!(results, 36, u16, "buy price (cps)"),
+				type_name:     results[37].get_value().to_owned(),
+				description:   results[38].get_value().to_owned(),
+				unknown_1:     get_value_as!(results, 39, u8, "unknown 1")
 			};
 
 			Result::Ok(item)
 		}
 	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::BaseItem;
+	use parser::SpaceSeparatedParseable;
+	use serializer::StringSerializableStructure;
+
+	const ITEM_LINE: &str = "410301 Blade 21 3 40 0 17 18 19 20 2 600 1500 4000 70 50 5 6 7 8 9 3000 4000 11 12 13 14 15 16 22 23 2 800 25 26 27 28 Blade Sharp~blade 1";
+
+	fn parse_item(line: &str) -> BaseItem {
+		match BaseItem::from_line(&String::from(line)) {
+			Result::Ok(item) => item,
+			Result::Err(_)   => panic!("failed to parse {}", line)
+		}
+	}
+
+	#[test]
+	fn parses_each_field_from_its_column() {
+		let item = parse_item(ITEM_LINE);
+
+		assert_eq!(item.id, 410301);
+		assert_eq!(item.requirements.lvl, 40);
+		assert_eq!(item.requirements.spi, 20);
+		assert_eq!(item.weight, 600);
+		assert_eq!(item.action_id, 4000);
+		assert_eq!(item.combat_stats.min_phys_atk, 50);
+		assert_eq!(item.amount_limit, 4000);
+		assert_eq!(item.magic3, 16);
+		assert_eq!(item.combat_stats.atk_speed, 800);
+		assert_eq!(item.description, "Sharp~blade");
+		assert_eq!(item.unknown_1, 1);
+	}
+
+	#[test]
+	fn round_trips_a_40_column_line() {
+		let item = parse_item(ITEM_LINE);
+
+		assert_eq!(item.serialize(), ITEM_LINE);
+	}
 }
\ No newline at end of file
@@ -0,0 +1,132 @@
+use item::{BaseItem, ItemCombatStats};
+use parser::{parse_space_separated_file, SpaceSeparatedParserError};
+
+pub const MAX_PLUS_LVL: u8 = 12;
+
+pub struct ItemAddition {
+	pub id:           u32,
+	pub item_type:    u32,
+	pub plus_lvl:     u8,
+	pub life:         u16,
+	pub max_phys_atk: u16,
+	pub min_phys_atk: u16,
+	pub phys_def:     u16,
+	pub magic_atk:    u16,
+	pub magic_def:    u16,
+	pub accuracy:     u8,
+	pub dodge:        u8
+}
+
+impl ItemAddition {
+	// Additions are shared by every quality of an item, so the quality digit is dropped.
+	pub fn get_type_key(item_id: &u32) -> u32 { *item_id - (*item_id % 10) }
+}
+
+impl ItemCombatStats {
+	pub fn with_addition(&self, addition: &ItemAddition) -> ItemCombatStats {
+		ItemCombatStats {
+			max_phys_atk: self.max_phys_atk.saturating_add(addition.max_phys_atk),
+			min_phys_atk: self.min_phys_atk.saturating_add(addition.min_phys_atk),
+			phys_def:     self.phys_def.saturating_add(addition.phys_def),
+			accuracy:     self.accuracy.saturating_add(addition.accuracy),
+			dodge:        self.dodge.saturating_add(addition.dodge),
+			magic_atk:    self.magic_atk.saturating_add(addition.magic_atk),
+			magic_def:    self.magic_def.saturating_add(addition.magic_def),
+			atk_range:    self.atk_range,
+			atk_speed:    self.atk_speed
+		}
+	}
+}
+
+pub struct ItemAdditionTable {
+	pub entries: Vec<ItemAddition>
+}
+
+impl ItemAdditionTable {
+	pub fn from_file_string(content: &str) -> Result<ItemAdditionTable, SpaceSeparatedParserError> {
+		Result::Ok(ItemAdditionTable {
+			entries: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn find(&self, item_id: &u32, plus_lvl: &u8) -> Option<&ItemAddition> {
+		let type_key = ItemAddition::get_type_key(item_id);
+
+		self.entries
+			.iter()
+			.find(|addition| addition.item_type == *item_id && addition.plus_lvl == *plus_lvl)
+			.or_else(|| self.entries
+				.iter()
+				.find(|addition| addition.item_type == type_key && addition.plus_lvl == *plus_lvl))
+	}
+
+	// Combat stats of the item once composed to the given plus level, if the level is valid
+	// and the table knows the item.
+	pub fn get_effective_combat_stats(&self, item: &BaseItem, plus_lvl: &u8) -> Option<ItemCombatStats> {
+		if *plus_lvl == 0 {
+			return Some(item.combat_stats.clone());
+		}
+
+		if *plus_lvl > MAX_PLUS_LVL {
+			return None;
+		}
+
+		self.find(&item.id, plus_lvl).map(|addition| item.combat_stats.with_addition(addition))
+	}
+}
+
+mod serializer {
+	use ::itemaddition::ItemAddition;
+	use ::serializer::{StringSerializableStructure, SpaceSeparatedSerializer};
+
+	impl StringSerializableStructure for ItemAddition {
+		fn serialize(&self) -> String {
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&self.id);
+			serializer.push_value(&self.item_type);
+			serializer.push_value(&self.plus_lvl);
+			serializer.push_value(&self.life);
+			serializer.push_value(&self.max_phys_atk);
+			serializer.push_value(&self.min_phys_atk);
+			serializer.push_value(&self.phys_def);
+			serializer.push_value(&self.magic_atk);
+			serializer.push_value(&self.magic_def);
+			serializer.push_value(&self.accuracy);
+			serializer.push_value(&self.dodge);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::*;
+	use parser::*;
+
+	impl SpaceSeparatedParseable<ItemAddition> for ItemAddition {
+		fn from_line(line: &String) -> Result<ItemAddition, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 11 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let addition = ItemAddition {
+				id:           get_value_as!(results, 0, u32, "id"),
+				item_type:    get_value_as!(results, 1, u32, "item type"),
+				plus_lvl:     get_value_as!(results, 2, u8, "plus level"),
+				life:         get_value_as!(results, 3, u16, "life"),
+				max_phys_atk: get_value_as!(results, 4, u16, "max phys attack"),
+				min_phys_atk: get_value_as!(results, 5, u16, "min phys attack"),
+				phys_def:     get_value_as!(results, 6, u16, "physical defense"),
+				magic_atk:    get_value_as!(results, 7, u16, "magic atk"),
+				magic_def:    get_value_as!(results, 8, u16, "magic def"),
+				accuracy:     get_value_as!(results, 9, u8, "accuracy"),
+				dodge:        get_value_as!(results, 10, u8, "dodge")
+			};
+
+			Result::Ok(addition)
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use item::BaseItem;
+use parser::{parse_space_separated_file, SpaceSeparatedParserError};
+use serializer::serialize_space_separated_file;
+
+#[derive(Clone)]
+pub struct ItemTable {
+	pub items: Vec<BaseItem>
+}
+
+impl ItemTable {
+	pub fn from_file_string(content: &str) -> Result<ItemTable, SpaceSeparatedParserError> {
+		Result::Ok(ItemTable {
+			items: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_space_separated_file(&self.items)
+	}
+
+	pub fn find(&self, id: &u32) -> Option<&BaseItem> {
+		self.items.iter().find(|item| item.id == *id)
+	}
+
+	pub fn contains(&self, id: &u32) -> bool { self.find(id).is_some() }
+
+	pub fn get_matching<F>(&self, predicate: F) -> Vec<&BaseItem>
+		where F: Fn(&BaseItem) -> bool {
+		self.items.iter().filter(|item| predicate(item)).collect()
+	}
+
+	pub fn get_stackable_items(&self) -> Vec<&BaseItem> {
+		self.get_matching(|item| item.get_type_mask().is_stackable)
+	}
+
+	pub fn get_auctionable_items(&self) -> Vec<&BaseItem> {
+		self.get_matching(|item| item.get_type_mask().is_auctionable)
+	}
+
+	pub fn get_bound_items(&self) -> Vec<&BaseItem> {
+		self.get_matching(|item| item.get_type_mask().is_bound)
+	}
+
+	pub fn get_quest_items(&self) -> Vec<&BaseItem> {
+		self.get_matching(|item| item.get_type_mask().is_quest_item)
+	}
+
+	pub fn get_expiring_items(&self) -> Vec<&BaseItem> {
+		self.get_matching(|item| item.get_type_mask().is_expiring)
+	}
+
+	// Items carrying type mask bits this crate does not know about yet.
+	pub fn get_items_with_unknown_type_bits(&self) -> Vec<&BaseItem> {
+		self.get_matching(|item| item.get_type_mask().unknown_bits != 0)
+	}
+}
\ No newline at end of file
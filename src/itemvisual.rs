@@ -0,0 +1,160 @@
+use item::BaseItem;
+use mesh::{EquipmentMesh, EquipmentMeshTable};
+use parser::{parse_ini_file, IniEntryParserError};
+use serializer::serialize_ini_file;
+
+// Armor and headgear come in colors 3 to 9, held by the hundreds digit of the item id.
+pub const MIN_COLOR: u8 = 3;
+pub const MAX_COLOR: u8 = 9;
+
+// Icon of an item from the client ItemMinIcon.ini.
+pub struct ItemIcon {
+	pub item_id: u32,
+	pub path:    String
+}
+
+pub struct ItemIconTable {
+	pub icons: Vec<ItemIcon>
+}
+
+impl ItemIconTable {
+	pub fn from_file_string(content: &str) -> Result<ItemIconTable, IniEntryParserError> {
+		Result::Ok(ItemIconTable {
+			icons: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_ini_file(&self.icons)
+	}
+
+	pub fn find(&self, item_id: &u32) -> Option<&ItemIcon> {
+		self.icons.iter().find(|icon| icon.item_id == *item_id)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MeshKind {
+	Armor,
+	Armet,
+	Weapon,
+	None
+}
+
+impl MeshKind {
+	pub fn from_item_id(id: &u32) -> MeshKind {
+		match *id / 10000 {
+			11 | 14       => MeshKind::Armet,
+			13            => MeshKind::Armor,
+			40..=59 | 90  => MeshKind::Weapon,
+			_             => MeshKind::None
+		}
+	}
+
+	pub fn is_colorable(&self) -> bool {
+		*self == MeshKind::Armor || *self == MeshKind::Armet
+	}
+}
+
+pub fn get_colored_item_id(id: &u32, color: &u8) -> u32 {
+	*id - ((*id / 100) % 10) * 100 + *color as u32 * 100
+}
+
+pub struct ColorVariant<'a> {
+	pub color: u8,
+	pub mesh:  &'a EquipmentMesh
+}
+
+pub struct ItemVisual<'a> {
+	pub icon:           Option<&'a str>,
+	pub mesh:           Option<&'a EquipmentMesh>,
+	pub color_variants: Vec<ColorVariant<'a>>
+}
+
+pub struct ItemVisualResolver<'a> {
+	pub icons:   &'a ItemIconTable,
+	pub armors:  &'a EquipmentMeshTable,
+	pub weapons: &'a EquipmentMeshTable,
+	pub armets:  &'a EquipmentMeshTable
+}
+
+impl<'a> ItemVisualResolver<'a> {
+	pub fn new(icons: &'a ItemIconTable, armors: &'a EquipmentMeshTable, weapons: &'a EquipmentMeshTable, armets: &'a EquipmentMeshTable) -> ItemVisualResolver<'a> {
+		ItemVisualResolver { icons, armors, weapons, armets }
+	}
+
+	fn get_mesh_table(&self, kind: &MeshKind) -> Option<&'a EquipmentMeshTable> {
+		match *kind {
+			MeshKind::Armor  => Some(self.armors),
+			MeshKind::Armet  => Some(self.armets),
+			MeshKind::Weapon => Some(self.weapons),
+			MeshKind::None   => None
+		}
+	}
+
+	// Meshes are shared by every quality of an item, so the quality digit is dropped if
+	// there is no mesh for the exact id.
+	fn find_mesh(table: &'a EquipmentMeshTable, id: &u32) -> Option<&'a EquipmentMesh> {
+		table.find(id).or_else(|| table.find(&(*id - *id % 10)))
+	}
+
+	pub fn resolve(&self, item: &BaseItem) -> ItemVisual<'a> {
+		let kind = MeshKind::from_item_id(&item.id);
+		let table = self.get_mesh_table(&kind);
+		let mut color_variants = Vec::new();
+
+		if let Some(table) = table {
+			if kind.is_colorable() {
+				for color in MIN_COLOR..=MAX_COLOR {
+					if let Some(mesh) = ItemVisualResolver::find_mesh(table, &get_colored_item_id(&item.id, &color)) {
+						color_variants.push(ColorVariant { color, mesh });
+					}
+				}
+			}
+		}
+
+		ItemVisual {
+			icon: self.icons.find(&item.id).map(|icon| icon.path.as_str()),
+			mesh: table.and_then(|table| ItemVisualResolver::find_mesh(table, &item.id)),
+			color_variants
+		}
+	}
+}
+
+mod serializer {
+	use super::ItemIcon;
+	use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for ItemIcon {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&format!("Item{}", self.item_id));
+			serializer.add_entry("Frame0", &self.path);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::ItemIcon;
+	use parser::{IniEntryParser, IniEntryParserError, IniEntryParseable};
+
+	impl IniEntryParseable<ItemIcon> for ItemIcon {
+		fn from_section_string(section: &String) -> Result<ItemIcon, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+			let section_name = parser.get_section();
+
+			let item_id = match section_name.trim_start_matches("Item").parse::<u32>() {
+				Result::Ok(item_id) => item_id,
+				Result::Err(_)      => return Result::Err(IniEntryParserError::InvalidCast(section_name))
+			};
+
+			Result::Ok(ItemIcon {
+				item_id,
+				path: parser.get_entry("Frame0")?
+			})
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,161 @@
+use monster::{BaseMonster, MonsterBattleRank};
+use parser::{parse_space_separated_file, SpaceSeparatedParserError};
+use serializer::serialize_space_separated_file;
+
+// Experience needed to go from a level to the next one.
+pub struct LevelExp {
+	pub lvl:              u16,
+	pub xp:               u64,
+	pub up_lvl_time_secs: u32
+}
+
+pub struct LevelExpTable {
+	pub levels: Vec<LevelExp>
+}
+
+impl LevelExpTable {
+	pub fn from_file_string(content: &str) -> Result<LevelExpTable, SpaceSeparatedParserError> {
+		Result::Ok(LevelExpTable {
+			levels: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_space_separated_file(&self.levels)
+	}
+
+	pub fn find(&self, lvl: &u16) -> Option<&LevelExp> {
+		self.levels.iter().find(|level| level.lvl == *lvl)
+	}
+
+	pub fn get_max_lvl(&self) -> u16 {
+		self.levels.iter().map(|level| level.lvl).max().unwrap_or(0)
+	}
+
+	// Applies gained experience, returning the new level and the experience held within it.
+	// Experience stops accumulating once the last level of the table is reached, short of
+	// what that level would need to go further.
+	pub fn add_xp(&self, lvl: &u16, xp: &u64, gained: &u64) -> (u16, u64) {
+		let max_lvl = self.get_max_lvl();
+		let mut lvl = *lvl;
+		let mut xp = xp.saturating_add(*gained);
+
+		while lvl < max_lvl {
+			match self.find(&lvl) {
+				Some(level) if level.xp != 0 && xp >= level.xp => xp -= level.xp,
+				_                                              => return (lvl, xp)
+			}
+
+			lvl += 1;
+		}
+
+		let max_xp = match self.find(&lvl) {
+			Some(level) => level.xp.saturating_sub(1),
+			None        => 0
+		};
+
+		(lvl, xp.min(max_xp))
+	}
+}
+
+// Percentage of experience kept depending on how far above (positive) or below (negative)
+// the player the monster is.
+pub fn get_lvl_diff_xp_pct(lvl_diff: &i32) -> u64 {
+	match *lvl_diff {
+		diff if diff >= 5  => 150,
+		diff if diff >= 3  => 120,
+		diff if diff >= -2 => 100,
+		diff if diff >= -5 => 50,
+		_                  => 10
+	}
+}
+
+impl MonsterBattleRank {
+	pub fn get_xp_pct(&self) -> u64 {
+		match *self {
+			MonsterBattleRank::Elite    => 150,
+			MonsterBattleRank::Champion => 200,
+			_                           => 100
+		}
+	}
+}
+
+impl BaseMonster {
+	// Experience earned by a player of the given level for killing the monster: its life,
+	// raised by its extra experience, then scaled by its rank and the level difference.
+	pub fn get_kill_xp(&self, player_lvl: &u16) -> u64 {
+		let lvl_diff = self.lvl as i32 - *player_lvl as i32;
+		let mut xp = self.max_life as u64 * (100 + self.extra_xp as u64) / 100;
+
+		xp = xp * self.get_battle_rank().get_xp_pct() / 100;
+		xp = xp * get_lvl_diff_xp_pct(&lvl_diff) / 100;
+
+		xp.max(1)
+	}
+}
+
+mod serializer {
+	use ::levelexp::LevelExp;
+	use ::serializer::{StringSerializableStructure, SpaceSeparatedSerializer};
+
+	impl StringSerializableStructure for LevelExp {
+		fn serialize(&self) -> String {
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&self.lvl);
+			serializer.push_value(&self.xp);
+			serializer.push_value(&self.up_lvl_time_secs);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::*;
+	use parser::*;
+
+	impl SpaceSeparatedParseable<LevelExp> for LevelExp {
+		fn from_line(line: &String) -> Result<LevelExp, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 3 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let level = LevelExp {
+				lvl:              get_value_as!(results, 0, u16, "level"),
+				xp:               get_value_as!(results, 1, u64, "experience"),
+				up_lvl_time_secs: get_value_as!(results, 2, u32, "up level time")
+			};
+
+			Result::Ok(level)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{LevelExp, LevelExpTable};
+
+	fn table() -> LevelExpTable {
+		LevelExpTable {
+			levels: vec![
+				LevelExp { lvl: 1, xp: 100, up_lvl_time_secs: 0 },
+				LevelExp { lvl: 2, xp: 200, up_lvl_time_secs: 0 }
+			]
+		}
+	}
+
+	#[test]
+	fn levels_up_through_the_table() {
+		assert_eq!(table().add_xp(&1, &0, &50), (1, 50));
+		assert_eq!(table().add_xp(&1, &50, &150), (2, 100));
+	}
+
+	#[test]
+	fn stops_at_the_last_level_of_the_table() {
+		assert_eq!(table().add_xp(&1, &0, &10_000), (2, 199));
+		assert_eq!(table().add_xp(&2, &0, &u64::MAX), (2, 199));
+	}
+}
\ No newline at end of file
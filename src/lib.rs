@@ -7,5 +7,33 @@ mod parser;
 mod serializer;
 
 mod item;
+mod itemtable;
 mod monster;
-mod magictype;
\ No newline at end of file
+mod monstertable;
+mod monstertype;
+mod generator;
+mod loot;
+mod levelexp;
+mod npc;
+mod shop;
+mod strres;
+mod overlay;
+mod gamemap;
+mod dmap;
+mod portal;
+mod magictype;
+mod magictypetable;
+mod magiceffect;
+mod reference;
+mod effect3d;
+mod sound;
+mod magicsim;
+mod itemaddition;
+mod gem;
+mod durability;
+mod price;
+mod consumable;
+mod weapon;
+mod mesh;
+mod appearance;
+mod itemvisual;
\ No newline at end of file
@@ -0,0 +1,307 @@
+use gem::{Gem, GemGrade, GemKind};
+use item::{is_equipment_id, ItemQuality};
+use itemtable::ItemTable;
+use parser::{parse_ini_file, IniEntryParserError};
+use serializer::serialize_ini_file;
+
+// Every chance of a loot table is given out of this scale.
+pub const CHANCE_SCALE: u32 = 10000;
+
+pub struct LootItem {
+	pub item_id: u32,
+	pub chance:  u32
+}
+
+pub struct QualityChances {
+	pub refined: u32,
+	pub unique:  u32,
+	pub elite:   u32,
+	pub superb:  u32
+}
+
+// Drops of a monster, keyed by the monster name as in monster.ini.
+pub struct MonsterLoot {
+	pub monster_name:  String,
+	pub money_min:     u32,
+	pub money_max:     u32,
+	pub items:         Vec<LootItem>,
+	pub quality:       QualityChances,
+	pub plus_chance:   u32,
+	pub socket_chance: u32,
+	pub gem_chance:    u32
+}
+
+// Quality, plus level and socket are only rolled for equipment; the quality always matches
+// the last digit of the item id.
+pub struct DroppedItem {
+	pub item_id:  u32,
+	pub quality:  ItemQuality,
+	pub plus_lvl: u8,
+	pub gem1:     Gem
+}
+
+pub struct LootDrop {
+	pub money: u32,
+	pub items: Vec<DroppedItem>
+}
+
+pub struct LootIssue {
+	pub monster_name: String,
+	pub item_id:      u32
+}
+
+// Small xorshift generator, so that simulations can be replayed from a seed.
+pub struct LootRoller {
+	state: u64
+}
+
+impl LootRoller {
+	pub fn new(seed: &u64) -> LootRoller {
+		LootRoller { state: if *seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { *seed } }
+	}
+
+	fn next(&mut self) -> u64 {
+		self.state ^= self.state << 13;
+		self.state ^= self.state >> 7;
+		self.state ^= self.state << 17;
+
+		self.state
+	}
+
+	// Rolls a value in [min, max].
+	pub fn roll_range(&mut self, min: &u32, max: &u32) -> u32 {
+		if *max <= *min {
+			return *min;
+		}
+
+		*min + (self.next() % (*max as u64 - *min as u64 + 1)) as u32
+	}
+
+	// Tests a chance out of CHANCE_SCALE.
+	pub fn roll_chance(&mut self, chance: &u32) -> bool {
+		(self.next() % CHANCE_SCALE as u64) < *chance as u64
+	}
+
+	pub fn roll(&mut self, loot: &MonsterLoot) -> LootDrop {
+		let money = self.roll_range(&loot.money_min, &loot.money_max);
+		let mut items = Vec::new();
+
+		for entry in &loot.items {
+			if !self.roll_chance(&entry.chance) {
+				continue;
+			}
+
+			if !is_equipment_id(&entry.item_id) {
+				items.push(DroppedItem {
+					item_id:  entry.item_id,
+					quality:  ItemQuality::from_item_id(&entry.item_id),
+					plus_lvl: 0,
+					gem1:     Gem::NoSocket
+				});
+				continue;
+			}
+
+			let item_id = self.roll_quality(&loot.quality).apply_to_item_id(&entry.item_id);
+			let plus_lvl = if self.roll_chance(&loot.plus_chance) { 1 } else { 0 };
+
+			let gem1 = if !self.roll_chance(&loot.socket_chance) {
+				Gem::NoSocket
+			} else if self.roll_chance(&loot.gem_chance) {
+				let kind = GemKind::ALL[(self.next() % GemKind::ALL.len() as u64) as usize];
+				Gem::Socketed(kind, GemGrade::Normal)
+			} else {
+				Gem::EmptySocket
+			};
+
+			items.push(DroppedItem { item_id, quality: ItemQuality::from_item_id(&item_id), plus_lvl, gem1 });
+		}
+
+		LootDrop { money, items }
+	}
+
+	// Qualities are rolled from the rarest to the most common.
+	fn roll_quality(&mut self, chances: &QualityChances) -> ItemQuality {
+		if self.roll_chance(&chances.superb) {
+			ItemQuality::Super
+		} else if self.roll_chance(&chances.elite) {
+			ItemQuality::Elite
+		} else if self.roll_chance(&chances.unique) {
+			ItemQuality::Unique
+		} else if self.roll_chance(&chances.refined) {
+			ItemQuality::Refined
+		} else {
+			ItemQuality::Normal
+		}
+	}
+}
+
+pub struct LootTable {
+	pub entries: Vec<MonsterLoot>
+}
+
+impl LootTable {
+	pub fn from_file_string(content: &str) -> Result<LootTable, IniEntryParserError> {
+		Result::Ok(LootTable {
+			entries: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_ini_file(&self.entries)
+	}
+
+	pub fn find(&self, monster_name: &str) -> Option<&MonsterLoot> {
+		self.entries.iter().find(|entry| entry.monster_name == monster_name)
+	}
+
+	// Reports every dropped item id missing from the item table.
+	pub fn validate(&self, items: &ItemTable) -> Vec<LootIssue> {
+		let mut issues = Vec::new();
+
+		for entry in &self.entries {
+			for item in &entry.items {
+				if !items.contains(&item.item_id) {
+					issues.push(LootIssue {
+						monster_name: entry.monster_name.clone(),
+						item_id:      item.item_id
+					});
+				}
+			}
+		}
+
+		issues
+	}
+}
+
+mod serializer {
+	use super::MonsterLoot;
+	use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for MonsterLoot {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.monster_name);
+			serializer.add_entry("MoneyMin", &self.money_min);
+			serializer.add_entry("MoneyMax", &self.money_max);
+			serializer.add_entry("Items", &self.items.len());
+
+			for (idx, item) in self.items.iter().enumerate() {
+				serializer.add_entry(&format!("Item{}", idx), &item.item_id);
+				serializer.add_entry(&format!("ItemChance{}", idx), &item.chance);
+			}
+
+			serializer.add_entry("RefinedChance", &self.quality.refined);
+			serializer.add_entry("UniqueChance", &self.quality.unique);
+			serializer.add_entry("EliteChance", &self.quality.elite);
+			serializer.add_entry("SuperChance", &self.quality.superb);
+			serializer.add_entry("PlusChance", &self.plus_chance);
+			serializer.add_entry("SocketChance", &self.socket_chance);
+			serializer.add_entry("GemChance", &self.gem_chance);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::{LootItem, MonsterLoot, QualityChances};
+	use parser::{IniEntryParser, IniEntryParserError, IniEntryParseable};
+
+	impl IniEntryParseable<MonsterLoot> for MonsterLoot {
+		fn from_section_string(section: &String) -> Result<MonsterLoot, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+			let item_count: usize = parser.get_entry_as("Items")?;
+			let mut items = Vec::new();
+
+			for idx in 0..item_count {
+				items.push(LootItem {
+					item_id: parser.get_entry_as(&format!("Item{}", idx))?,
+					chance:  parser.get_entry_as(&format!("ItemChance{}", idx))?
+				});
+			}
+
+			let loot = MonsterLoot {
+				monster_name:  parser.get_section(),
+				money_min:     parser.get_entry_as("MoneyMin")?,
+				money_max:     parser.get_entry_as("MoneyMax")?,
+				items,
+
+				quality:       QualityChances {
+					refined: parser.get_entry_as("RefinedChance")?,
+					unique:  parser.get_entry_as("UniqueChance")?,
+					elite:   parser.get_entry_as("EliteChance")?,
+					superb:  parser.get_entry_as("SuperChance")?
+				},
+
+				plus_chance:   parser.get_entry_as("PlusChance")?,
+				socket_chance: parser.get_entry_as("SocketChance")?,
+				gem_chance:    parser.get_entry_as("GemChance")?
+			};
+
+			Result::Ok(loot)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn loot() -> MonsterLoot {
+		MonsterLoot {
+			monster_name:  String::from("Pheasant"),
+			money_min:     10,
+			money_max:     100,
+			items:         vec![
+				LootItem { item_id: 1000000, chance: CHANCE_SCALE },
+				LootItem { item_id: 410300, chance: CHANCE_SCALE }
+			],
+			quality:       QualityChances { refined: 5000, unique: 5000, elite: 5000, superb: 5000 },
+			plus_chance:   5000,
+			socket_chance: 5000,
+			gem_chance:    5000
+		}
+	}
+
+	fn get_summary(drop: &LootDrop) -> Vec<(u32, u8, Gem)> {
+		drop.items.iter().map(|item| (item.item_id, item.plus_lvl, item.gem1)).collect()
+	}
+
+	#[test]
+	fn replays_the_same_drops_from_a_seed() {
+		let loot = loot();
+
+		for seed in 0..20 {
+			let first = LootRoller::new(&seed).roll(&loot);
+			let second = LootRoller::new(&seed).roll(&loot);
+
+			assert_eq!(first.money, second.money);
+			assert!(get_summary(&first) == get_summary(&second));
+		}
+	}
+
+	#[test]
+	fn only_rolls_quality_for_equipment() {
+		let loot = loot();
+		let mut roller = LootRoller::new(&42);
+		let mut qualities = Vec::new();
+
+		for _ in 0..100 {
+			let drop = roller.roll(&loot);
+
+			let potion = &drop.items[0];
+			assert_eq!(potion.item_id, 1000000);
+			assert!(potion.quality == ItemQuality::Normal);
+			assert_eq!(potion.plus_lvl, 0);
+			assert!(potion.gem1 == Gem::NoSocket);
+
+			let blade = &drop.items[1];
+			assert_eq!(blade.item_id / 10, 41030);
+			assert!(blade.quality == ItemQuality::from_item_id(&blade.item_id));
+			qualities.push(blade.item_id % 10);
+		}
+
+		assert!(qualities.iter().any(|digit| *digit != 0));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use magictypetable::MagicTypeTable;
+use parser::{parse_ini_file, IniEntryParserError};
+use serializer::serialize_ini_file;
+
+// Visual details of a skill level from the client MagicEffect.ini.
+pub struct MagicEffectEntry {
+	pub id:            u32,
+	pub skill_id:      u32,
+	pub skill_lvl:     u8,
+	pub intone_effect: String,
+	pub intone_sfx:    String,
+	pub sender_effect: String,
+	pub sender_sfx:    String,
+	pub target_effect: String,
+	pub target_sfx:    String,
+	pub ground_effect: String,
+	pub trace_effect:  String,
+	pub target_delay:  u32
+}
+
+pub struct MagicEffectTable {
+	pub entries: Vec<MagicEffectEntry>
+}
+
+impl MagicEffectTable {
+	pub fn from_file_string(content: &str) -> Result<MagicEffectTable, IniEntryParserError> {
+		Result::Ok(MagicEffectTable {
+			entries: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_ini_file(&self.entries)
+	}
+
+	pub fn find(&self, skill_id: &u32, skill_lvl: &u8) -> Option<&MagicEffectEntry> {
+		self.entries.iter().find(|entry| entry.skill_id == *skill_id && entry.skill_lvl == *skill_lvl)
+	}
+
+	// Skill id and level of every magictype row without a matching effect section.
+	pub fn get_missing_effects(&self, magic_types: &MagicTypeTable) -> Vec<(u32, u8)> {
+		magic_types.entries
+			.iter()
+			.filter(|entry| self.find(&entry.id, &entry.skill_lvl).is_none())
+			.map(|entry| (entry.id, entry.skill_lvl))
+			.collect()
+	}
+}
+
+mod serializer {
+	use super::MagicEffectEntry;
+	use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for MagicEffectEntry {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.id);
+			serializer.add_entry("Type", &self.skill_id);
+			serializer.add_entry("Level", &self.skill_lvl);
+			serializer.add_entry("IntoneEffect", &self.intone_effect);
+			serializer.add_entry("IntoneSound", &self.intone_sfx);
+			serializer.add_entry("SenderEffect", &self.sender_effect);
+			serializer.add_entry("SenderSound", &self.sender_sfx);
+			serializer.add_entry("TargetEffect", &self.target_effect);
+			serializer.add_entry("TargetSound", &self.target_sfx);
+			serializer.add_entry("GroundEffect", &self.ground_effect);
+			serializer.add_entry("TraceEffect", &self.trace_effect);
+			serializer.add_entry("TargetDelay", &self.target_delay);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::MagicEffectEntry;
+	use parser::{IniEntryParser, IniEntryParserError, IniEntryParseable};
+
+	impl IniEntryParseable<MagicEffectEntry> for MagicEffectEntry {
+		fn from_section_string(section: &String) -> Result<MagicEffectEntry, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+
+			let entry = MagicEffectEntry {
+				id:            parser.get_section_id()?,
+				skill_id:      parser.get_entry_as("Type")?,
+				skill_lvl:     parser.get_entry_as("Level")?,
+				intone_effect: parser.get_entry("IntoneEffect")?,
+				intone_sfx:    parser.get_entry("IntoneSound")?,
+				sender_effect: parser.get_entry("SenderEffect")?,
+				sender_sfx:    parser.get_entry("SenderSound")?,
+				target_effect: parser.get_entry("TargetEffect")?,
+				target_sfx:    parser.get_entry("TargetSound")?,
+				ground_effect: parser.get_entry("GroundEffect")?,
+				trace_effect:  parser.get_entry("TraceEffect")?,
+				target_delay:  parser.get_entry_as("TargetDelay")?
+			};
+
+			Result::Ok(entry)
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,274 @@
+use magictype::{BaseMagicTypeEntry, MagicSort};
+
+pub enum MagicPower {
+	Flat(i32),
+	Percent(i32),
+	Set(u32)
+}
+
+impl MagicPower {
+	// Powers from 30000 encode a percentage offset by 30000, and powers from -30000 down
+	// a fixed value the stat is set to, offset by -30000.
+	pub fn from_raw(power: &i32) -> MagicPower {
+		if *power >= 30000 {
+			MagicPower::Percent(*power - 30000)
+		} else if *power <= -30000 {
+			MagicPower::Set((-(*power as i64) - 30000) as u32)
+		} else {
+			MagicPower::Flat(*power)
+		}
+	}
+
+	pub fn get_as_raw(&self) -> i32 {
+		match *self {
+			MagicPower::Flat(value)    => value,
+			MagicPower::Percent(value) => value + 30000,
+			MagicPower::Set(value)     => (-(value as i64) - 30000) as i32
+		}
+	}
+
+	// Applies the power on top of a base value (attack, max life, ...).
+	pub fn apply_to(&self, base: &u32) -> u32 {
+		match *self {
+			MagicPower::Flat(value)    => clamp_to_u32(*base as i64 + value as i64),
+			MagicPower::Percent(value) => clamp_to_u32(*base as i64 * value as i64 / 100),
+			MagicPower::Set(value)     => value
+		}
+	}
+
+	// Resolves the power on its own, percentages being taken from the given reference.
+	pub fn resolve_against(&self, reference: &u32) -> u32 {
+		match *self {
+			MagicPower::Flat(value)    => clamp_to_u32(value as i64),
+			MagicPower::Percent(value) => clamp_to_u32(*reference as i64 * value as i64 / 100),
+			MagicPower::Set(value)     => value
+		}
+	}
+
+	// Amount a stat gains from the power. Set powers move the stat to their value, so the
+	// gain depends on its current value.
+	pub fn get_gain(&self, current: &u32, reference: &u32) -> u32 {
+		match *self {
+			MagicPower::Set(value) => value.saturating_sub(*current),
+			_                      => self.resolve_against(reference)
+		}
+	}
+
+	pub fn get_loss(&self, current: &u32, reference: &u32) -> u32 {
+		match *self {
+			MagicPower::Set(value) => current.saturating_sub(value),
+			_                      => self.resolve_against(reference)
+		}
+	}
+}
+
+fn clamp_to_u32(value: i64) -> u32 {
+	if value < 0 {
+		0
+	} else if value > u32::MAX as i64 {
+		u32::MAX
+	} else {
+		value as u32
+	}
+}
+
+pub struct CombatantStats {
+	pub lvl:          u16,
+	pub life:         u32,
+	pub max_life:     u32,
+	pub mana:         u32,
+	pub max_mana:     u32,
+	pub min_phys_atk: u32,
+	pub max_phys_atk: u32,
+	pub magic_atk:    u32,
+	pub phys_def:     u32,
+	pub magic_def:    u32,
+	pub accuracy:     u8,
+	pub dodge:        u8
+}
+
+pub enum SkillEffectKind {
+	Damage,
+	Heal,
+	ManaRestore,
+	None
+}
+
+pub struct SkillSimulation {
+	pub kind:        SkillEffectKind,
+	pub min_amount:  u32,
+	pub max_amount:  u32,
+	pub hit_chance:  u8,
+	pub is_in_range: bool,
+	pub area_range:  u32
+}
+
+impl SkillSimulation {
+	// Expected amount over many casts, misses included.
+	pub fn get_expected_amount(&self) -> u32 {
+		let average = (self.min_amount as u64 + self.max_amount as u64) / 2;
+
+		(average * self.hit_chance as u64 / 100) as u32
+	}
+}
+
+pub fn simulate_skill(attacker: &CombatantStats, target: &CombatantStats, entry: &BaseMagicTypeEntry, distance: &u32) -> SkillSimulation {
+	let power = MagicPower::from_raw(&entry.power);
+	let sort = entry.get_sort();
+	let is_physical = entry.hits_with_weapon > 0;
+
+	let (kind, min_amount, max_amount) = match sort {
+		MagicSort::Recruit => {
+			let amount = power.get_gain(&target.life, &target.max_life);
+			(SkillEffectKind::Heal, amount, amount)
+		},
+
+		MagicSort::AddMana => {
+			let amount = power.get_gain(&target.mana, &target.max_mana);
+			(SkillEffectKind::ManaRestore, amount, amount)
+		},
+
+		// Life decrease ignores defense, percentages being taken from the current life.
+		MagicSort::DecLife => {
+			let amount = power.get_loss(&target.life, &target.life);
+			(SkillEffectKind::Damage, amount, amount)
+		},
+
+		ref damaging if damaging.is_damaging() => {
+			if is_physical {
+				(
+					SkillEffectKind::Damage,
+					power.apply_to(&attacker.min_phys_atk).saturating_sub(target.phys_def),
+					power.apply_to(&attacker.max_phys_atk).saturating_sub(target.phys_def)
+				)
+			} else {
+				let amount = power.apply_to(&attacker.magic_atk).saturating_sub(target.magic_def);
+				(SkillEffectKind::Damage, amount, amount)
+			}
+		},
+
+		_ => (SkillEffectKind::None, 0, 0)
+	};
+
+	SkillSimulation {
+		hit_chance:  get_hit_chance(attacker, target, entry, &kind),
+		is_in_range: is_in_cast_range(entry, distance),
+		area_range:  entry.range,
+		kind,
+		min_amount,
+		max_amount
+	}
+}
+
+// Hit chance in percent. An accuracy of 0 on the entry means the skill always lands
+// unless dodged; weapon based skills are further reduced by the target dodge.
+pub fn get_hit_chance(attacker: &CombatantStats, target: &CombatantStats, entry: &BaseMagicTypeEntry, kind: &SkillEffectKind) -> u8 {
+	if let SkillEffectKind::Damage = *kind {
+		let base: u32 = if entry.accuracy == 0 || entry.accuracy > 100 { 100 } else { entry.accuracy as u32 };
+
+		if entry.hits_with_weapon == 0 {
+			return base as u8;
+		}
+
+		let dodge = (target.dodge as u32).saturating_sub(attacker.accuracy as u32).min(100);
+
+		return (base * (100 - dodge) / 100) as u8;
+	}
+
+	100
+}
+
+// Tests if a target at the given distance (in cells) can be reached by the skill.
+pub fn is_in_cast_range(entry: &BaseMagicTypeEntry, distance: &u32) -> bool {
+	if entry.max_distance == 0 {
+		return *distance <= 1;
+	}
+
+	*distance <= entry.max_distance as u32
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parser::SpaceSeparatedParseable;
+
+	fn entry(sort: u8, power: i32, accuracy: u8, hits_with_weapon: u8) -> BaseMagicTypeEntry {
+		let line = format!(
+			"1000 {} Skill 1 0 0 0 0 0 {} 0 {} 0 0 3 0 0 0 0 0 0 0 0 0 0 0 0 0 {} 0 0 0 0 0 Short Desc a b c d 0 e f g h 0 0 0",
+			sort, power, accuracy, hits_with_weapon
+		);
+
+		match BaseMagicTypeEntry::from_line(&line) {
+			Result::Ok(entry) => entry,
+			Result::Err(_)    => panic!("failed to parse {}", line)
+		}
+	}
+
+	fn combatant() -> CombatantStats {
+		CombatantStats {
+			lvl:          100,
+			life:         500,
+			max_life:     1000,
+			mana:         100,
+			max_mana:     400,
+			min_phys_atk: 800,
+			max_phys_atk: 1000,
+			magic_atk:    1000,
+			phys_def:     200,
+			magic_def:    100,
+			accuracy:     10,
+			dodge:        30
+		}
+	}
+
+	#[test]
+	fn decodes_each_power_encoding() {
+		assert!(matches!(MagicPower::from_raw(&500), MagicPower::Flat(500)));
+		assert!(matches!(MagicPower::from_raw(&-500), MagicPower::Flat(-500)));
+		assert!(matches!(MagicPower::from_raw(&30000), MagicPower::Percent(0)));
+		assert!(matches!(MagicPower::from_raw(&30150), MagicPower::Percent(150)));
+		assert!(matches!(MagicPower::from_raw(&-30000), MagicPower::Set(0)));
+		assert!(matches!(MagicPower::from_raw(&-30100), MagicPower::Set(100)));
+
+		for raw in [500, -500, 30000, 30150, -30000, -30100].iter() {
+			assert_eq!(MagicPower::from_raw(raw).get_as_raw(), *raw);
+		}
+	}
+
+	#[test]
+	fn applies_each_power_encoding() {
+		assert_eq!(MagicPower::Flat(-600).apply_to(&500), 0);
+		assert_eq!(MagicPower::Percent(0).apply_to(&500), 0);
+		assert_eq!(MagicPower::Percent(150).apply_to(&500), 750);
+		assert_eq!(MagicPower::Set(100).apply_to(&500), 100);
+		assert_eq!(MagicPower::Set(100).get_loss(&500, &1000), 400);
+		assert_eq!(MagicPower::Set(800).get_gain(&500, &1000), 300);
+	}
+
+	#[test]
+	fn simulates_damage_heal_and_set_life() {
+		let attacker = combatant();
+		let target = combatant();
+
+		let magic = simulate_skill(&attacker, &target, &entry(1, 30150, 0, 0), &2);
+		assert_eq!((magic.min_amount, magic.max_amount), (1400, 1400));
+		assert!(magic.is_in_range);
+
+		let heal = simulate_skill(&attacker, &target, &entry(2, 30050, 0, 0), &1);
+		assert!(matches!(heal.kind, SkillEffectKind::Heal));
+		assert_eq!(heal.min_amount, 500);
+
+		let set_life = simulate_skill(&attacker, &target, &entry(26, -30001, 0, 0), &1);
+		assert_eq!(set_life.min_amount, 499);
+	}
+
+	#[test]
+	fn reduces_hit_chance_by_dodge_for_weapon_skills_only() {
+		let attacker = combatant();
+		let target = combatant();
+
+		assert_eq!(simulate_skill(&attacker, &target, &entry(1, 100, 0, 0), &1).hit_chance, 100);
+		assert_eq!(simulate_skill(&attacker, &target, &entry(1, 100, 0, 1), &1).hit_chance, 80);
+		assert_eq!(simulate_skill(&attacker, &target, &entry(1, 100, 50, 1), &1).hit_chance, 40);
+	}
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+#[derive(Clone)]
 pub struct BaseMagicTypeEntry {
     pub id:                      u32,
     pub action_sort:             u8,
@@ -37,6 +38,121 @@ pub struct BaseMagicTypeEntry {
     pub is_staggering:           bool
 }
 
+impl BaseMagicTypeEntry {
+    pub fn get_sort(&self) -> MagicSort { MagicSort::from_byte(&self.action_sort) }
+}
+
+pub enum MagicSort {
+    Attack,
+    Recruit,
+    Cross,
+    Fan,
+    Bomb,
+    AttachStatus,
+    DetachStatus,
+    Square,
+    JumpAttack,
+    RandomTransport,
+    DispatchXp,
+    Collide,
+    SerialCut,
+    Line,
+    AtkRange,
+    AtkStatus,
+    CallTeamMember,
+    RecordTransportSpell,
+    Transform,
+    AddMana,
+    LayTrap,
+    Dance,
+    CallPet,
+    Vampire,
+    Instead,
+    DecLife,
+    Unknown(u8)
+}
+
+impl MagicSort {
+    pub fn from_byte(sort: &u8) -> MagicSort {
+        match *sort {
+            1  => MagicSort::Attack,
+            2  => MagicSort::Recruit,
+            3  => MagicSort::Cross,
+            4  => MagicSort::Fan,
+            5  => MagicSort::Bomb,
+            6  => MagicSort::AttachStatus,
+            7  => MagicSort::DetachStatus,
+            8  => MagicSort::Square,
+            9  => MagicSort::JumpAttack,
+            10 => MagicSort::RandomTransport,
+            11 => MagicSort::DispatchXp,
+            12 => MagicSort::Collide,
+            13 => MagicSort::SerialCut,
+            14 => MagicSort::Line,
+            15 => MagicSort::AtkRange,
+            16 => MagicSort::AtkStatus,
+            17 => MagicSort::CallTeamMember,
+            18 => MagicSort::RecordTransportSpell,
+            19 => MagicSort::Transform,
+            20 => MagicSort::AddMana,
+            21 => MagicSort::LayTrap,
+            22 => MagicSort::Dance,
+            23 => MagicSort::CallPet,
+            24 => MagicSort::Vampire,
+            25 => MagicSort::Instead,
+            26 => MagicSort::DecLife,
+            other => MagicSort::Unknown(other)
+        }
+    }
+
+    pub fn get_as_byte(&self) -> u8 {
+        match *self {
+            MagicSort::Attack               => 1,
+            MagicSort::Recruit              => 2,
+            MagicSort::Cross                => 3,
+            MagicSort::Fan                  => 4,
+            MagicSort::Bomb                 => 5,
+            MagicSort::AttachStatus         => 6,
+            MagicSort::DetachStatus         => 7,
+            MagicSort::Square               => 8,
+            MagicSort::JumpAttack           => 9,
+            MagicSort::RandomTransport      => 10,
+            MagicSort::DispatchXp           => 11,
+            MagicSort::Collide              => 12,
+            MagicSort::SerialCut            => 13,
+            MagicSort::Line                 => 14,
+            MagicSort::AtkRange             => 15,
+            MagicSort::AtkStatus            => 16,
+            MagicSort::CallTeamMember       => 17,
+            MagicSort::RecordTransportSpell => 18,
+            MagicSort::Transform            => 19,
+            MagicSort::AddMana              => 20,
+            MagicSort::LayTrap              => 21,
+            MagicSort::Dance                => 22,
+            MagicSort::CallPet              => 23,
+            MagicSort::Vampire              => 24,
+            MagicSort::Instead              => 25,
+            MagicSort::DecLife              => 26,
+            MagicSort::Unknown(other)       => other
+        }
+    }
+
+    // Tests if the sort deals damage to its targets.
+    pub fn is_damaging(&self) -> bool {
+        matches!(*self,
+            MagicSort::Attack | MagicSort::Cross | MagicSort::Fan | MagicSort::Bomb |
+            MagicSort::Square | MagicSort::JumpAttack | MagicSort::Collide | MagicSort::SerialCut |
+            MagicSort::Line | MagicSort::AtkRange | MagicSort::AtkStatus | MagicSort::Vampire |
+            MagicSort::DecLife)
+    }
+
+    // Tests if the sort restores life or mana to its targets.
+    pub fn is_restoring(&self) -> bool {
+        matches!(*self, MagicSort::Recruit | MagicSort::AddMana)
+    }
+}
+
+#[derive(Clone)]
 pub struct MagicTargetFlags {
     pub is_body_target:          bool,
     pub is_passive_target:       bool,
@@ -69,6 +185,7 @@ impl MagicTargetFlags {
     }
 }
 
+#[derive(Clone)]
 pub struct MagicRequirements {
     pub job_required:            u32,
     pub xp_required:             u64,
@@ -77,6 +194,7 @@ pub struct MagicRequirements {
     pub uses_item:               u8
 }
 
+#[derive(Clone)]
 pub struct MagicEffects {
     pub intone_effect:           String,
     pub intone_sfx:              String,
@@ -88,6 +206,7 @@ pub struct MagicEffects {
     pub target_sfx:              String
 }
 
+#[derive(Clone)]
 pub struct MagicCosts {
     pub mp_cost:                 u16,
     pub stamina_cost:            u8
@@ -240,4 +359,34 @@ mod parser {
             Result::Ok(magic_type_entry)
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BaseMagicTypeEntry;
+    use parser::SpaceSeparatedParseable;
+
+    #[test]
+    fn parses_each_field_from_its_column() {
+        let line = String::from("1000 1 Thunder 1 0 0 0 3 36 30110 0 100 0 0 18 0 0 2000 40 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 Thunder~bolt Strikes~lightning intone intone_sfx sender sender_sfx 300 thunder thunder_sfx ground trace 0 1 1");
+
+        let entry = match BaseMagicTypeEntry::from_line(&line) {
+            Result::Ok(entry) => entry,
+            Result::Err(_)    => panic!("failed to parse {}", line)
+        };
+
+        assert_eq!(entry.id, 1000);
+        assert_eq!(entry.skill_name, "Thunder");
+        assert!(entry.is_offensive_on_use);
+        assert_eq!(entry.skill_lvl, 3);
+        assert_eq!(entry.costs.mp_cost, 36);
+        assert_eq!(entry.power, 30110);
+        assert_eq!(entry.max_distance, 18);
+        assert_eq!(entry.requirements.xp_required, 2000);
+        assert_eq!(entry.desc, "Strikes~lightning");
+        assert_eq!(entry.target_delay, 300);
+        assert_eq!(entry.effects.target_effect, "thunder");
+        assert!(!entry.screen_represent);
+        assert!(entry.is_staggering);
+    }
 }
\ No newline at end of file
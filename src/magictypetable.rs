@@ -0,0 +1,28 @@
+use magictype::BaseMagicTypeEntry;
+use parser::{parse_space_separated_file, SpaceSeparatedParserError};
+use serializer::serialize_space_separated_file;
+
+#[derive(Clone)]
+pub struct MagicTypeTable {
+	pub entries: Vec<BaseMagicTypeEntry>
+}
+
+impl MagicTypeTable {
+	pub fn from_file_string(content: &str) -> Result<MagicTypeTable, SpaceSeparatedParserError> {
+		Result::Ok(MagicTypeTable {
+			entries: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_space_separated_file(&self.entries)
+	}
+
+	pub fn find(&self, id: &u32, skill_lvl: &u8) -> Option<&BaseMagicTypeEntry> {
+		self.entries.iter().find(|entry| entry.id == *id && entry.skill_lvl == *skill_lvl)
+	}
+
+	pub fn get_levels(&self, id: &u32) -> Vec<&BaseMagicTypeEntry> {
+		self.entries.iter().filter(|entry| entry.id == *id).collect()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use parser::{parse_ini_file, IniEntryParserError};
+use serializer::serialize_ini_file;
+
+// Client equipment mesh tables (Armor.ini, Weapon.ini, Armet.ini), keyed by look id.
+pub struct EquipmentMeshPart {
+	pub mesh:        u32,
+	pub texture:     u32,
+	pub mix_texture: u32,
+	pub mix_option:  u32,
+	pub asb:         u8,
+	pub adb:         u8
+}
+
+pub struct EquipmentMesh {
+	pub id:    u32,
+	pub parts: Vec<EquipmentMeshPart>
+}
+
+pub struct EquipmentMeshTable {
+	pub entries: Vec<EquipmentMesh>
+}
+
+impl EquipmentMeshTable {
+	pub fn from_file_string(content: &str) -> Result<EquipmentMeshTable, IniEntryParserError> {
+		Result::Ok(EquipmentMeshTable {
+			entries: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_ini_file(&self.entries)
+	}
+
+	pub fn find(&self, id: &u32) -> Option<&EquipmentMesh> {
+		self.entries.iter().find(|entry| entry.id == *id)
+	}
+}
+
+mod serializer {
+	use super::EquipmentMesh;
+	use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for EquipmentMesh {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.id);
+			serializer.add_entry("Part", &self.parts.len());
+
+			for (idx, part) in self.parts.iter().enumerate() {
+				serializer.add_entry(&format!("Mesh{}", idx), &part.mesh);
+				serializer.add_entry(&format!("Texture{}", idx), &part.texture);
+				serializer.add_entry(&format!("MixTex{}", idx), &part.mix_texture);
+				serializer.add_entry(&format!("MixOpt{}", idx), &part.mix_option);
+				serializer.add_entry(&format!("Asb{}", idx), &part.asb);
+				serializer.add_entry(&format!("Adb{}", idx), &part.adb);
+			}
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::{EquipmentMesh, EquipmentMeshPart};
+	use parser::{IniEntryParser, IniEntryParserError, IniEntryParseable};
+
+	impl IniEntryParseable<EquipmentMesh> for EquipmentMesh {
+		fn from_section_string(section: &String) -> Result<EquipmentMesh, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+			let part_count: usize = parser.get_entry_as("Part")?;
+			let mut parts = Vec::new();
+
+			for idx in 0..part_count {
+				parts.push(EquipmentMeshPart {
+					mesh:        parser.get_entry_as(&format!("Mesh{}", idx))?,
+					texture:     parser.get_entry_as(&format!("Texture{}", idx))?,
+					mix_texture: parser.get_entry_as_or(&format!("MixTex{}", idx), 0)?,
+					mix_option:  parser.get_entry_as_or(&format!("MixOpt{}", idx), 0)?,
+					asb:         parser.get_entry_as_or(&format!("Asb{}", idx), 5)?,
+					adb:         parser.get_entry_as_or(&format!("Adb{}", idx), 6)?
+				});
+			}
+
+			let id = parser.get_section_id()?;
+
+			Result::Ok(EquipmentMesh { id, parts })
+		}
+	}
+}
\ No newline at end of file
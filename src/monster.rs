@@ -25,6 +25,183 @@ pub struct BaseMonster {
 	pub born_sound:     String
 }
 
+impl BaseMonster {
+	pub fn get_body_type(&self) -> MonsterBodyType { MonsterBodyType::from_byte(&self.body_type) }
+
+	pub fn get_action_flags(&self) -> MonsterActionFlags { MonsterActionFlags::from_byte(&self.act_res_ctrl) }
+
+	pub fn get_anti_flags(&self) -> MonsterAntiFlags { MonsterAntiFlags::from_byte(&self.anti_type) }
+
+	pub fn get_stc_type(&self) -> MonsterStcType { MonsterStcType::from_byte(&self.stc_type) }
+
+	pub fn get_battle_rank(&self) -> MonsterBattleRank { MonsterBattleRank::from_byte(&self.battle_lvl) }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MonsterBodyType {
+	None,
+	Humanoid,
+	Beast,
+	Flying,
+	Ghost,
+	Unknown(u8)
+}
+
+impl MonsterBodyType {
+	pub fn from_byte(body_type: &u8) -> MonsterBodyType {
+		match *body_type {
+			0     => MonsterBodyType::None,
+			1     => MonsterBodyType::Humanoid,
+			2     => MonsterBodyType::Beast,
+			3     => MonsterBodyType::Flying,
+			4     => MonsterBodyType::Ghost,
+			other => MonsterBodyType::Unknown(other)
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		match *self {
+			MonsterBodyType::None           => 0,
+			MonsterBodyType::Humanoid       => 1,
+			MonsterBodyType::Beast          => 2,
+			MonsterBodyType::Flying         => 3,
+			MonsterBodyType::Ghost          => 4,
+			MonsterBodyType::Unknown(other) => other
+		}
+	}
+}
+
+// Actions the monster model has animations for.
+pub struct MonsterActionFlags {
+	pub can_walk:     bool,
+	pub can_run:      bool,
+	pub can_attack:   bool,
+	pub can_cast:     bool,
+	pub has_death:    bool,
+	pub has_born:     bool,
+	pub unknown_bits: u8
+}
+
+impl MonsterActionFlags {
+	pub const KNOWN_BITS: u8 = 0x3F;
+
+	pub fn from_byte(flags: &u8) -> MonsterActionFlags {
+		MonsterActionFlags {
+			can_walk:     (flags & 0x01) > 0,
+			can_run:      (flags & 0x02) > 0,
+			can_attack:   (flags & 0x04) > 0,
+			can_cast:     (flags & 0x08) > 0,
+			has_death:    (flags & 0x10) > 0,
+			has_born:     (flags & 0x20) > 0,
+			unknown_bits: flags & !MonsterActionFlags::KNOWN_BITS
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		let mut flag: u8 = self.unknown_bits & !MonsterActionFlags::KNOWN_BITS;
+		flag |= if self.can_walk { 0x01 } else { 0x00 };
+		flag |= if self.can_run { 0x02 } else { 0x00 };
+		flag |= if self.can_attack { 0x04 } else { 0x00 };
+		flag |= if self.can_cast { 0x08 } else { 0x00 };
+		flag |= if self.has_death { 0x10 } else { 0x00 };
+		flag |= if self.has_born { 0x20 } else { 0x00 };
+
+		flag
+	}
+}
+
+// Families the monster belongs to, which some skills and items deal extra damage to.
+pub struct MonsterAntiFlags {
+	pub is_undead:     bool,
+	pub is_beast:      bool,
+	pub is_demon:      bool,
+	pub is_elemental:  bool,
+	pub unknown_bits:  u8
+}
+
+impl MonsterAntiFlags {
+	pub const KNOWN_BITS: u8 = 0x0F;
+
+	pub fn from_byte(flags: &u8) -> MonsterAntiFlags {
+		MonsterAntiFlags {
+			is_undead:    (flags & 0x01) > 0,
+			is_beast:     (flags & 0x02) > 0,
+			is_demon:     (flags & 0x04) > 0,
+			is_elemental: (flags & 0x08) > 0,
+			unknown_bits: flags & !MonsterAntiFlags::KNOWN_BITS
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		let mut flag: u8 = self.unknown_bits & !MonsterAntiFlags::KNOWN_BITS;
+		flag |= if self.is_undead { 0x01 } else { 0x00 };
+		flag |= if self.is_beast { 0x02 } else { 0x00 };
+		flag |= if self.is_demon { 0x04 } else { 0x00 };
+		flag |= if self.is_elemental { 0x08 } else { 0x00 };
+
+		flag
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MonsterStcType {
+	Normal,
+	Guard,
+	Boss,
+	Pet,
+	Unknown(u8)
+}
+
+impl MonsterStcType {
+	pub fn from_byte(stc_type: &u8) -> MonsterStcType {
+		match *stc_type {
+			0     => MonsterStcType::Normal,
+			1     => MonsterStcType::Guard,
+			2     => MonsterStcType::Boss,
+			3     => MonsterStcType::Pet,
+			other => MonsterStcType::Unknown(other)
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		match *self {
+			MonsterStcType::Normal         => 0,
+			MonsterStcType::Guard          => 1,
+			MonsterStcType::Boss           => 2,
+			MonsterStcType::Pet            => 3,
+			MonsterStcType::Unknown(other) => other
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MonsterBattleRank {
+	Normal,
+	Elite,
+	Champion,
+	Unknown(u8)
+}
+
+impl MonsterBattleRank {
+	pub fn from_byte(battle_lvl: &u8) -> MonsterBattleRank {
+		match *battle_lvl {
+			0     => MonsterBattleRank::Normal,
+			1     => MonsterBattleRank::Elite,
+			2     => MonsterBattleRank::Champion,
+			other => MonsterBattleRank::Unknown(other)
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		match *self {
+			MonsterBattleRank::Normal         => 0,
+			MonsterBattleRank::Elite          => 1,
+			MonsterBattleRank::Champion       => 2,
+			MonsterBattleRank::Unknown(other) => other
+		}
+	}
+}
+
 mod serializer {
     use super::BaseMonster;
     use serializer::{IniEntrySerializer, StringSerializableStructure};
@@ -0,0 +1,29 @@
+use monster::BaseMonster;
+use parser::{parse_ini_file, IniEntryParserError};
+use serializer::serialize_ini_file;
+
+pub struct MonsterTable {
+	pub monsters: Vec<BaseMonster>
+}
+
+impl MonsterTable {
+	pub fn from_file_string(content: &str) -> Result<MonsterTable, IniEntryParserError> {
+		Result::Ok(MonsterTable {
+			monsters: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize(&self) -> String {
+		serialize_ini_file(&self.monsters)
+	}
+
+	pub fn find_by_name(&self, name: &str) -> Option<&BaseMonster> {
+		self.monsters.iter().find(|monster| monster.name == name)
+	}
+
+	pub fn find_by_type_id(&self, type_id: &u32) -> Option<&BaseMonster> {
+		self.monsters
+			.iter()
+			.find(|monster| monster.type_id.parse::<u32>().ok() == Some(*type_id))
+	}
+}
\ No newline at end of file
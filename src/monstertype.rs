@@ -0,0 +1,224 @@
+use monster::BaseMonster;
+use monstertable::MonsterTable;
+use parser::{parse_ini_file, parse_space_separated_file, IniEntryParserError, SpaceSeparatedParserError};
+use serializer::{serialize_ini_file, serialize_space_separated_file};
+
+// Server side gameplay definition of a monster (cq_monstertype). The client side look
+// lives in monster.ini as a BaseMonster, matched on the type id or the name.
+pub struct MonsterType {
+	pub id:             u32,
+	pub name:           String,
+	pub look_face:      u32,
+	pub lvl:            u16,
+	pub life:           u32,
+	pub max_phys_atk:   u32,
+	pub min_phys_atk:   u32,
+	pub phys_def:       u32,
+	pub magic_def:      u32,
+	pub accuracy:       u16,
+	pub dodge:          u16,
+	pub atk_range:      u8,
+	pub view_range:     u8,
+	pub atk_speed:      u32,
+	pub move_speed:     u32,
+	pub escape_life:    u32,
+	pub magic_type:     u32,
+	pub drop_money:     u32,
+	pub drop_item_type: u32,
+	pub extra_xp:       u16
+}
+
+impl MonsterType {
+	// Tests if the monster flees at the given life.
+	pub fn is_escaping(&self, life: &u32) -> bool { *life <= self.escape_life }
+
+	pub fn find_base_monster<'a>(&self, monsters: &'a MonsterTable) -> Option<&'a BaseMonster> {
+		monsters
+			.find_by_type_id(&self.id)
+			.or_else(|| monsters.find_by_name(&self.name))
+	}
+}
+
+pub struct JoinedMonsterType<'a> {
+	pub monster_type: &'a MonsterType,
+	pub monster:      Option<&'a BaseMonster>
+}
+
+pub struct MonsterTypeTable {
+	pub types: Vec<MonsterType>
+}
+
+impl MonsterTypeTable {
+	pub fn from_space_separated_string(content: &str) -> Result<MonsterTypeTable, SpaceSeparatedParserError> {
+		Result::Ok(MonsterTypeTable {
+			types: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn from_ini_string(content: &str) -> Result<MonsterTypeTable, IniEntryParserError> {
+		Result::Ok(MonsterTypeTable {
+			types: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize_space_separated(&self) -> String {
+		let entries: Vec<SpaceSeparatedMonsterType> = self.types.iter().map(SpaceSeparatedMonsterType).collect();
+
+		serialize_space_separated_file(&entries)
+	}
+
+	pub fn serialize_ini(&self) -> String {
+		serialize_ini_file(&self.types)
+	}
+
+	pub fn find(&self, id: &u32) -> Option<&MonsterType> {
+		self.types.iter().find(|monster_type| monster_type.id == *id)
+	}
+
+	pub fn join<'a>(&'a self, monsters: &'a MonsterTable) -> Vec<JoinedMonsterType<'a>> {
+		self.types
+			.iter()
+			.map(|monster_type| JoinedMonsterType {
+				monster_type,
+				monster: monster_type.find_base_monster(monsters)
+			})
+			.collect()
+	}
+}
+
+struct SpaceSeparatedMonsterType<'a>(&'a MonsterType);
+
+mod serializer {
+	use super::{MonsterType, SpaceSeparatedMonsterType};
+	use serializer::{IniEntrySerializer, SpaceSeparatedSerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for MonsterType {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.id);
+			serializer.add_entry("Name", &self.name);
+			serializer.add_entry("LookFace", &self.look_face);
+			serializer.add_entry("Level", &self.lvl);
+			serializer.add_entry("Life", &self.life);
+			serializer.add_entry("AttackMax", &self.max_phys_atk);
+			serializer.add_entry("AttackMin", &self.min_phys_atk);
+			serializer.add_entry("Defence", &self.phys_def);
+			serializer.add_entry("MagicDef", &self.magic_def);
+			serializer.add_entry("Dexterity", &self.accuracy);
+			serializer.add_entry("Dodge", &self.dodge);
+			serializer.add_entry("AttackRange", &self.atk_range);
+			serializer.add_entry("ViewRange", &self.view_range);
+			serializer.add_entry("AttackSpeed", &self.atk_speed);
+			serializer.add_entry("MoveSpeed", &self.move_speed);
+			serializer.add_entry("EscapeLife", &self.escape_life);
+			serializer.add_entry("MagicType", &self.magic_type);
+			serializer.add_entry("DropMoney", &self.drop_money);
+			serializer.add_entry("DropItemType", &self.drop_item_type);
+			serializer.add_entry("ExtraExp", &self.extra_xp);
+
+			serializer.get_buffer()
+		}
+	}
+
+	impl<'a> StringSerializableStructure for SpaceSeparatedMonsterType<'a> {
+		fn serialize(&self) -> String {
+			let monster_type = self.0;
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&monster_type.id);
+			serializer.push_value(&monster_type.name);
+			serializer.push_value(&monster_type.look_face);
+			serializer.push_value(&monster_type.lvl);
+			serializer.push_value(&monster_type.life);
+			serializer.push_value(&monster_type.max_phys_atk);
+			serializer.push_value(&monster_type.min_phys_atk);
+			serializer.push_value(&monster_type.phys_def);
+			serializer.push_value(&monster_type.magic_def);
+			serializer.push_value(&monster_type.accuracy);
+			serializer.push_value(&monster_type.dodge);
+			serializer.push_value(&monster_type.atk_range);
+			serializer.push_value(&monster_type.view_range);
+			serializer.push_value(&monster_type.atk_speed);
+			serializer.push_value(&monster_type.move_speed);
+			serializer.push_value(&monster_type.escape_life);
+			serializer.push_value(&monster_type.magic_type);
+			serializer.push_value(&monster_type.drop_money);
+			serializer.push_value(&monster_type.drop_item_type);
+			serializer.push_value(&monster_type.extra_xp);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::MonsterType;
+	use parser::*;
+
+	impl SpaceSeparatedParseable<MonsterType> for MonsterType {
+		fn from_line(line: &String) -> Result<MonsterType, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 20 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let monster_type = MonsterType {
+				id:             get_value_as!(results, 0, u32, "id"),
+				name:           results[1].get_value().to_owned(),
+				look_face:      get_value_as!(results, 2, u32, "look face"),
+				lvl:            get_value_as!(results, 3, u16, "level"),
+				life:           get_value_as!(results, 4, u32, "life"),
+				max_phys_atk:   get_value_as!(results, 5, u32, "max phys attack"),
+				min_phys_atk:   get_value_as!(results, 6, u32, "min phys attack"),
+				phys_def:       get_value_as!(results, 7, u32, "physical defense"),
+				magic_def:      get_value_as!(results, 8, u32, "magic def"),
+				accuracy:       get_value_as!(results, 9, u16, "accuracy"),
+				dodge:          get_value_as!(results, 10, u16, "dodge"),
+				atk_range:      get_value_as!(results, 11, u8, "attack range"),
+				view_range:     get_value_as!(results, 12, u8, "view range"),
+				atk_speed:      get_value_as!(results, 13, u32, "attack speed"),
+				move_speed:     get_value_as!(results, 14, u32, "move speed"),
+				escape_life:    get_value_as!(results, 15, u32, "escape life"),
+				magic_type:     get_value_as!(results, 16, u32, "magic type"),
+				drop_money:     get_value_as!(results, 17, u32, "drop money"),
+				drop_item_type: get_value_as!(results, 18, u32, "drop item type"),
+				extra_xp:       get_value_as!(results, 19, u16, "extra experience")
+			};
+
+			Result::Ok(monster_type)
+		}
+	}
+
+	impl IniEntryParseable<MonsterType> for MonsterType {
+		fn from_section_string(section: &String) -> Result<MonsterType, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+
+			let monster_type = MonsterType {
+				id:             parser.get_section_id()?,
+				name:           parser.get_entry("Name")?,
+				look_face:      parser.get_entry_as("LookFace")?,
+				lvl:            parser.get_entry_as("Level")?,
+				life:           parser.get_entry_as("Life")?,
+				max_phys_atk:   parser.get_entry_as("AttackMax")?,
+				min_phys_atk:   parser.get_entry_as("AttackMin")?,
+				phys_def:       parser.get_entry_as("Defence")?,
+				magic_def:      parser.get_entry_as("MagicDef")?,
+				accuracy:       parser.get_entry_as("Dexterity")?,
+				dodge:          parser.get_entry_as("Dodge")?,
+				atk_range:      parser.get_entry_as("AttackRange")?,
+				view_range:     parser.get_entry_as("ViewRange")?,
+				atk_speed:      parser.get_entry_as("AttackSpeed")?,
+				move_speed:     parser.get_entry_as("MoveSpeed")?,
+				escape_life:    parser.get_entry_as("EscapeLife")?,
+				magic_type:     parser.get_entry_as("MagicType")?,
+				drop_money:     parser.get_entry_as("DropMoney")?,
+				drop_item_type: parser.get_entry_as("DropItemType")?,
+				extra_xp:       parser.get_entry_as("ExtraExp")?
+			};
+
+			Result::Ok(monster_type)
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,164 @@
+use parser::{parse_ini_file, parse_space_separated_file, IniEntryParserError, SpaceSeparatedParserError};
+use serializer::{serialize_ini_file, serialize_space_separated_file};
+
+pub const NPC_TASK_COUNT: usize = 8;
+
+pub struct Npc {
+	pub id:        u32,
+	pub name:      String,
+	pub npc_type:  u16,
+	pub look_face: u32,
+	pub map_id:    u32,
+	pub x:         u16,
+	pub y:         u16,
+	pub task_ids:  [u32; NPC_TASK_COUNT]
+}
+
+impl Npc {
+	// Task ids actually set, 0 meaning an unused slot.
+	pub fn get_tasks(&self) -> Vec<u32> {
+		self.task_ids.iter().filter(|task_id| **task_id != 0).cloned().collect()
+	}
+}
+
+pub struct NpcTable {
+	pub npcs: Vec<Npc>
+}
+
+impl NpcTable {
+	pub fn from_space_separated_string(content: &str) -> Result<NpcTable, SpaceSeparatedParserError> {
+		Result::Ok(NpcTable {
+			npcs: parse_space_separated_file(content)?
+		})
+	}
+
+	pub fn from_ini_string(content: &str) -> Result<NpcTable, IniEntryParserError> {
+		Result::Ok(NpcTable {
+			npcs: parse_ini_file(content)?
+		})
+	}
+
+	pub fn serialize_space_separated(&self) -> String {
+		let entries: Vec<SpaceSeparatedNpc> = self.npcs.iter().map(SpaceSeparatedNpc).collect();
+
+		serialize_space_separated_file(&entries)
+	}
+
+	pub fn serialize_ini(&self) -> String {
+		serialize_ini_file(&self.npcs)
+	}
+
+	pub fn find(&self, id: &u32) -> Option<&Npc> {
+		self.npcs.iter().find(|npc| npc.id == *id)
+	}
+
+	pub fn get_on_map(&self, map_id: &u32) -> Vec<&Npc> {
+		self.npcs.iter().filter(|npc| npc.map_id == *map_id).collect()
+	}
+}
+
+struct SpaceSeparatedNpc<'a>(&'a Npc);
+
+mod serializer {
+	use super::{Npc, SpaceSeparatedNpc};
+	use serializer::{IniEntrySerializer, SpaceSeparatedSerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for Npc {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&self.id);
+			serializer.add_entry("Name", &self.name);
+			serializer.add_entry("Type", &self.npc_type);
+			serializer.add_entry("LookFace", &self.look_face);
+			serializer.add_entry("MapID", &self.map_id);
+			serializer.add_entry("CellX", &self.x);
+			serializer.add_entry("CellY", &self.y);
+
+			for (idx, task_id) in self.task_ids.iter().enumerate() {
+				serializer.add_entry(&format!("Task{}", idx), task_id);
+			}
+
+			serializer.get_buffer()
+		}
+	}
+
+	impl<'a> StringSerializableStructure for SpaceSeparatedNpc<'a> {
+		fn serialize(&self) -> String {
+			let npc = self.0;
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&npc.id);
+			serializer.push_value(&npc.name);
+			serializer.push_value(&npc.npc_type);
+			serializer.push_value(&npc.look_face);
+			serializer.push_value(&npc.map_id);
+			serializer.push_value(&npc.x);
+			serializer.push_value(&npc.y);
+
+			for task_id in npc.task_ids.iter() {
+				serializer.push_value(task_id);
+			}
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::{Npc, NPC_TASK_COUNT};
+	use parser::*;
+
+	impl SpaceSeparatedParseable<Npc> for Npc {
+		fn from_line(line: &String) -> Result<Npc, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 7 + NPC_TASK_COUNT {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let mut task_ids = [0; NPC_TASK_COUNT];
+
+			for (idx, task_id) in task_ids.iter_mut().enumerate() {
+				*task_id = get_value_as!(results, 7 + idx, u32, format!("task {}", idx));
+			}
+
+			let npc = Npc {
+				id:        get_value_as!(results, 0, u32, "id"),
+				name:      results[1].get_value().to_owned(),
+				npc_type:  get_value_as!(results, 2, u16, "type"),
+				look_face: get_value_as!(results, 3, u32, "look face"),
+				map_id:    get_value_as!(results, 4, u32, "map id"),
+				x:         get_value_as!(results, 5, u16, "cell x"),
+				y:         get_value_as!(results, 6, u16, "cell y"),
+				task_ids
+			};
+
+			Result::Ok(npc)
+		}
+	}
+
+	impl IniEntryParseable<Npc> for Npc {
+		fn from_section_string(section: &String) -> Result<Npc, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+			let mut task_ids = [0; NPC_TASK_COUNT];
+
+			for (idx, task_id) in task_ids.iter_mut().enumerate() {
+				*task_id = parser.get_entry_as_or(&format!("Task{}", idx), 0)?;
+			}
+
+			let npc = Npc {
+				id:        parser.get_section_id()?,
+				name:      parser.get_entry("Name")?,
+				npc_type:  parser.get_entry_as("Type")?,
+				look_face: parser.get_entry_as("LookFace")?,
+				map_id:    parser.get_entry_as("MapID")?,
+				x:         parser.get_entry_as("CellX")?,
+				y:         parser.get_entry_as("CellY")?,
+				task_ids
+			};
+
+			Result::Ok(npc)
+		}
+	}
+}
\ No newline at end of file
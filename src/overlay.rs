@@ -0,0 +1,263 @@
+use itemtable::ItemTable;
+use magictypetable::MagicTypeTable;
+use parser::{parse_space_separated_file, SpaceSeparatedParserError};
+use serializer::serialize_space_separated_file;
+
+// Translated texts of an item, one line per item id in a language file.
+pub struct ItemTextOverlay {
+	pub id:          u32,
+	pub name:        String,
+	pub type_name:   String,
+	pub description: String
+}
+
+// Translated texts of a skill level, descriptions changing from a level to another.
+pub struct MagicTextOverlay {
+	pub id:         u32,
+	pub skill_lvl:  u8,
+	pub skill_name: String,
+	pub short_desc: String,
+	pub desc:       String
+}
+
+pub struct MissingTranslations {
+	pub language:  String,
+	pub item_ids:     Vec<u32>,
+	pub magic_levels: Vec<(u32, u8)>
+}
+
+impl MissingTranslations {
+	pub fn is_empty(&self) -> bool { self.item_ids.is_empty() && self.magic_levels.is_empty() }
+
+	// Plain text report, one "item <id>" or "magic <id> <level>" line per missing translation.
+	pub fn serialize(&self) -> String {
+		let mut lines = vec![format!("[{}]", self.language)];
+
+		lines.extend(self.item_ids.iter().map(|id| format!("item {}", id)));
+		lines.extend(self.magic_levels.iter().map(|(id, skill_lvl)| format!("magic {} {}", id, skill_lvl)));
+
+		lines.join("\n")
+	}
+}
+
+pub struct LanguageOverlay {
+	pub language: String,
+	pub items:    Vec<ItemTextOverlay>,
+	pub magics:   Vec<MagicTextOverlay>
+}
+
+impl LanguageOverlay {
+	pub fn from_file_strings(language: &str, items_content: &str, magics_content: &str) -> Result<LanguageOverlay, SpaceSeparatedParserError> {
+		Result::Ok(LanguageOverlay {
+			language: String::from(language),
+			items:    parse_space_separated_file(items_content)?,
+			magics:   parse_space_separated_file(magics_content)?
+		})
+	}
+
+	// Builds an overlay holding the texts of the canonical tables, to start a translation from.
+	pub fn from_tables(language: &str, items: &ItemTable, magic_types: &MagicTypeTable) -> LanguageOverlay {
+		LanguageOverlay {
+			language: String::from(language),
+			items:    items.items
+				.iter()
+				.map(|item| ItemTextOverlay {
+					id:          item.id,
+					name:        item.name.clone(),
+					type_name:   item.type_name.clone(),
+					description: item.description.clone()
+				})
+				.collect(),
+			magics:   magic_types.entries
+				.iter()
+				.map(|entry| MagicTextOverlay {
+					id:         entry.id,
+					skill_lvl:  entry.skill_lvl,
+					skill_name: entry.skill_name.clone(),
+					short_desc: entry.short_desc.clone(),
+					desc:       entry.desc.clone()
+				})
+				.collect()
+		}
+	}
+
+	pub fn serialize_items(&self) -> String {
+		serialize_space_separated_file(&self.items)
+	}
+
+	pub fn serialize_magics(&self) -> String {
+		serialize_space_separated_file(&self.magics)
+	}
+
+	pub fn find_item(&self, id: &u32) -> Option<&ItemTextOverlay> {
+		self.items.iter().find(|item| item.id == *id)
+	}
+
+	pub fn find_magic(&self, id: &u32, skill_lvl: &u8) -> Option<&MagicTextOverlay> {
+		self.magics.iter().find(|magic| magic.id == *id && magic.skill_lvl == *skill_lvl)
+	}
+
+	// Copy of the canonical table with the translated texts; untranslated items keep their
+	// canonical texts, and the canonical table is left untouched.
+	pub fn get_localized_items(&self, items: &ItemTable) -> ItemTable {
+		let mut localized = items.clone();
+
+		for item in localized.items.iter_mut() {
+			if let Some(overlay) = self.find_item(&item.id) {
+				item.name = overlay.name.clone();
+				item.type_name = overlay.type_name.clone();
+				item.description = overlay.description.clone();
+			}
+		}
+
+		localized
+	}
+
+	pub fn get_localized_magic_types(&self, magic_types: &MagicTypeTable) -> MagicTypeTable {
+		let mut localized = magic_types.clone();
+
+		for entry in localized.entries.iter_mut() {
+			if let Some(overlay) = self.find_magic(&entry.id, &entry.skill_lvl) {
+				entry.skill_name = overlay.skill_name.clone();
+				entry.short_desc = overlay.short_desc.clone();
+				entry.desc = overlay.desc.clone();
+			}
+		}
+
+		localized
+	}
+
+	pub fn get_missing_translations(&self, items: &ItemTable, magic_types: &MagicTypeTable) -> MissingTranslations {
+		let mut magic_levels: Vec<(u32, u8)> = magic_types.entries
+			.iter()
+			.map(|entry| (entry.id, entry.skill_lvl))
+			.filter(|(id, skill_lvl)| self.find_magic(id, skill_lvl).is_none())
+			.collect();
+
+		magic_levels.sort_unstable();
+		magic_levels.dedup();
+
+		MissingTranslations {
+			language:  self.language.clone(),
+			item_ids:  items.items
+				.iter()
+				.map(|item| item.id)
+				.filter(|id| self.find_item(id).is_none())
+				.collect(),
+			magic_levels
+		}
+	}
+}
+
+mod serializer {
+	use ::overlay::{ItemTextOverlay, MagicTextOverlay};
+	use ::serializer::{StringSerializableStructure, SpaceSeparatedSerializer};
+
+	impl StringSerializableStructure for ItemTextOverlay {
+		fn serialize(&self) -> String {
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&self.id);
+			serializer.push_value(&self.name);
+			serializer.push_value(&self.type_name);
+			serializer.push_value(&self.description);
+
+			serializer.get_buffer()
+		}
+	}
+
+	impl StringSerializableStructure for MagicTextOverlay {
+		fn serialize(&self) -> String {
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&self.id);
+			serializer.push_value(&self.skill_lvl);
+			serializer.push_value(&self.skill_name);
+			serializer.push_value(&self.short_desc);
+			serializer.push_value(&self.desc);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::*;
+	use parser::*;
+
+	impl SpaceSeparatedParseable<ItemTextOverlay> for ItemTextOverlay {
+		fn from_line(line: &String) -> Result<ItemTextOverlay, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 4 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			Result::Ok(ItemTextOverlay {
+				id:          get_value_as!(results, 0, u32, "id"),
+				name:        results[1].get_value().to_owned(),
+				type_name:   results[2].get_value().to_owned(),
+				description: results[3].get_value().to_owned()
+			})
+		}
+	}
+
+	impl SpaceSeparatedParseable<MagicTextOverlay> for MagicTextOverlay {
+		fn from_line(line: &String) -> Result<MagicTextOverlay, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 5 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			Result::Ok(MagicTextOverlay {
+				id:         get_value_as!(results, 0, u32, "id"),
+				skill_lvl:  get_value_as!(results, 1, u8, "skill lvl"),
+				skill_name: results[2].get_value().to_owned(),
+				short_desc: results[3].get_value().to_owned(),
+				desc:       results[4].get_value().to_owned()
+			})
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::LanguageOverlay;
+	use itemtable::ItemTable;
+	use magictype::BaseMagicTypeEntry;
+	use magictypetable::MagicTypeTable;
+	use parser::SpaceSeparatedParseable;
+
+	fn magic_entry(skill_lvl: u8, desc: &str) -> BaseMagicTypeEntry {
+		let line = format!(
+			"1000 1 Skill 1 0 0 0 {} 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 Short {} a b c d 0 e f g h 0 0 0",
+			skill_lvl, desc
+		);
+
+		match BaseMagicTypeEntry::from_line(&line) {
+			Result::Ok(entry) => entry,
+			Result::Err(_)    => panic!("failed to parse {}", line)
+		}
+	}
+
+	#[test]
+	fn keeps_the_texts_of_each_skill_level() {
+		let items = ItemTable { items: Vec::new() };
+		let magic_types = MagicTypeTable {
+			entries: vec![magic_entry(0, "Level0~desc"), magic_entry(1, "Level1~desc")]
+		};
+
+		let mut overlay = LanguageOverlay::from_tables("fr", &items, &magic_types);
+		overlay.magics[1].desc = String::from("Niveau1~desc");
+
+		let localized = overlay.get_localized_magic_types(&magic_types);
+
+		assert_eq!(localized.entries[0].desc, "Level0~desc");
+		assert_eq!(localized.entries[1].desc, "Niveau1~desc");
+		assert_eq!(magic_types.entries[1].desc, "Level1~desc");
+
+		overlay.magics.remove(0);
+		assert_eq!(overlay.get_missing_translations(&items, &magic_types).magic_levels, vec![(1000, 0)]);
+	}
+}
\ No newline at end of file
@@ -1,10 +1,11 @@
 use std::str::FromStr;
 use ini::Ini;
 
+// Reads the column at the given index as the requested type.
 macro_rules! get_value_as {
 	( $results:expr, $idx:expr, $type:tt, $field_name:expr ) => {
 		{
-			if let Result::Ok(result) = $results[0].get_value_as::<$type>() {
+			if let Result::Ok(result) = $results[$idx].get_value_as::<$type>() {
 				result
 			} else {
 				return Result::Err(SpaceSeparatedParserError::InvalidCast(String::from($field_name).to_owned()));
@@ -29,6 +30,30 @@ pub trait SpaceSeparatedParseable<T> {
 	fn from_line(line: &String) -> Result<T, SpaceSeparatedParserError>;
  }
 
+// Parses every entry of a space separated file, skipping the amount header if present.
+pub fn parse_space_separated_file<T>(content: &str) -> Result<Vec<T>, SpaceSeparatedParserError>
+	where T: SpaceSeparatedParseable<T> {
+	let mut entries = Vec::new();
+
+	for (line_idx, line) in content.lines().enumerate() {
+		let line = line.trim();
+
+		if line.is_empty() || (line_idx == 0 && is_amount_header(line)) {
+			continue;
+		}
+
+		entries.push(T::from_line(&String::from(line))?);
+	}
+
+	Result::Ok(entries)
+}
+
+fn is_amount_header(line: &str) -> bool {
+	let parts = get_space_separated_values(&String::from(line));
+
+	parts.len() == 1 && parts[0].get_value_as::<usize>().is_ok()
+}
+
 pub struct StringParserResult {
 	pub value: String
 }
@@ -55,6 +80,35 @@ pub trait IniEntryParseable<T> {
 	fn from_section_string(section: &String) -> Result<T, IniEntryParserError>;
 }
 
+// Splits an ini file into one string per section, each holding its header and entries.
+pub fn split_ini_sections(content: &str) -> Vec<String> {
+	let mut sections: Vec<String> = Vec::new();
+
+	for line in content.lines() {
+		let line = line.trim();
+
+		if line.starts_with('[') {
+			sections.push(String::from(line));
+		} else if let Some(section) = sections.last_mut() {
+			if !line.is_empty() && !line.starts_with(';') {
+				section.push('\n');
+				section.push_str(line);
+			}
+		}
+	}
+
+	sections
+}
+
+// Parses every section of an ini file.
+pub fn parse_ini_file<T>(content: &str) -> Result<Vec<T>, IniEntryParserError>
+	where T: IniEntryParseable<T> {
+	split_ini_sections(content)
+		.iter()
+		.map(|section| T::from_section_string(section))
+		.collect()
+}
+
 pub struct IniEntryParser {
 	ini: Ini,
 	section_name: String
@@ -85,6 +139,15 @@ impl IniEntryParser {
 		self.section_name.parse::<T>()
 	}
 
+	// Section name read as the id of the entry it holds.
+	pub fn get_section_id<T>(&self) -> Result<T, IniEntryParserError>
+		where T: FromStr {
+		match self.get_section_as() {
+			Result::Ok(id) => Result::Ok(id),
+			Result::Err(_) => Result::Err(IniEntryParserError::InvalidCast(self.get_section()))
+		}
+	}
+
 	pub fn get_entry_as<T>(&self, entry_key: &str) -> Result<T, IniEntryParserError>
 		where T: FromStr {
 
@@ -104,6 +167,15 @@ impl IniEntryParser {
 
 	}
 
+	// Like get_entry_as, falling back to the default only when the key is missing.
+	pub fn get_entry_as_or<T>(&self, entry_key: &str, default: T) -> Result<T, IniEntryParserError>
+		where T: FromStr {
+		match self.get_entry_as(entry_key) {
+			Result::Err(IniEntryParserError::MissingKey(_)) => Result::Ok(default),
+			result                                          => result
+		}
+	}
+
 	pub fn get_entry(&self, entry_key: &str) -> Result<String, IniEntryParserError> {
 		if let Some(val) = self.ini.get_from(Option::Some(self.section_name.clone()), &entry_key) {
 			return Result::Ok(val.to_string());
@@ -112,4 +184,102 @@ impl IniEntryParser {
 		Result::Err(IniEntryParserError::MissingKey(entry_key.to_string()))
 	}
 
+}
+
+pub enum BinaryParserError {
+	UnexpectedEof(usize),
+	InvalidString(usize),
+	InvalidValue(String)
+}
+
+pub trait BinaryParseable<T> {
+	fn from_reader(reader: &mut BinaryReader) -> Result<T, BinaryParserError>;
+}
+
+// Little endian reader over a binary file.
+pub struct BinaryReader<'a> {
+	buffer:   &'a [u8],
+	position: usize
+}
+
+impl<'a> BinaryReader<'a> {
+	pub fn new(buffer: &'a [u8]) -> BinaryReader<'a> {
+		BinaryReader {
+			buffer,
+			position: 0
+		}
+	}
+
+	pub fn get_position(&self) -> usize {
+		self.position
+	}
+
+	pub fn get_remaining(&self) -> usize {
+		self.buffer.len() - self.position
+	}
+
+	// Capacity to reserve for a count of entries read from the file, each taking at least the
+	// given size. The count is bounded by the remaining bytes, so corrupted counts fail on read
+	// instead of on allocation.
+	pub fn get_bounded_capacity(&self, count: usize, min_entry_size: usize) -> usize {
+		count.min(self.get_remaining() / min_entry_size)
+	}
+
+	pub fn is_at_end(&self) -> bool {
+		self.position >= self.buffer.len()
+	}
+
+	pub fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], BinaryParserError> {
+		if self.buffer.len() - self.position < length {
+			return Result::Err(BinaryParserError::UnexpectedEof(self.position));
+		}
+
+		let bytes = &self.buffer[self.position..self.position + length];
+		self.position += length;
+
+		Result::Ok(bytes)
+	}
+
+	pub fn skip(&mut self, length: usize) -> Result<(), BinaryParserError> {
+		self.read_bytes(length).map(|_| ())
+	}
+
+	pub fn read_u8(&mut self) -> Result<u8, BinaryParserError> {
+		Result::Ok(self.read_bytes(1)?[0])
+	}
+
+	pub fn read_u16(&mut self) -> Result<u16, BinaryParserError> {
+		let bytes = self.read_bytes(2)?;
+
+		Result::Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+	}
+
+	pub fn read_i16(&mut self) -> Result<i16, BinaryParserError> {
+		Result::Ok(self.read_u16()? as i16)
+	}
+
+	pub fn read_u32(&mut self) -> Result<u32, BinaryParserError> {
+		let bytes = self.read_bytes(4)?;
+
+		Result::Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+	}
+
+	// Reads a string stored in a fixed size buffer, padded with zeroes.
+	pub fn read_fixed_string(&mut self, length: usize) -> Result<String, BinaryParserError> {
+		let position = self.position;
+		let bytes = self.read_bytes(length)?;
+		let end = bytes.iter().position(|byte| *byte == 0).unwrap_or(length);
+
+		match String::from_utf8(bytes[..end].to_vec()) {
+			Result::Ok(value) => Result::Ok(value),
+			Result::Err(_)    => Result::Err(BinaryParserError::InvalidString(position))
+		}
+	}
+
+	// Reads a string prefixed by its length as an u32.
+	pub fn read_sized_string(&mut self) -> Result<String, BinaryParserError> {
+		let length = self.read_u32()? as usize;
+
+		self.read_fixed_string(length)
+	}
 }
\ No newline at end of file
@@ -0,0 +1,278 @@
+use std::collections::{HashMap, VecDeque};
+
+use dmap::DMap;
+use parser::{parse_space_separated_file, SpaceSeparatedParserError};
+use serializer::serialize_space_separated_file;
+
+// Arrival point of a map portal (cq_portal), identified on its map by the portal index.
+pub struct Portal {
+	pub id:         u32,
+	pub map_id:     u32,
+	pub x:          u16,
+	pub y:          u16,
+	pub portal_idx: u32
+}
+
+// Link from a DMap portal of a map to a portal of another map (cq_passway). The passway
+// index matches the id of the portal in the DMap of the source map.
+pub struct Passway {
+	pub id:                u32,
+	pub map_id:            u32,
+	pub passway_idx:       u32,
+	pub target_map_id:     u32,
+	pub target_portal_idx: u32
+}
+
+pub struct PortalTable {
+	pub portals:   Vec<Portal>,
+	pub passways:  Vec<Passway>
+}
+
+impl PortalTable {
+	pub fn from_file_strings(portals_content: &str, passways_content: &str) -> Result<PortalTable, SpaceSeparatedParserError> {
+		Result::Ok(PortalTable {
+			portals:  parse_space_separated_file(portals_content)?,
+			passways: parse_space_separated_file(passways_content)?
+		})
+	}
+
+	pub fn serialize_portals(&self) -> String {
+		serialize_space_separated_file(&self.portals)
+	}
+
+	pub fn serialize_passways(&self) -> String {
+		serialize_space_separated_file(&self.passways)
+	}
+
+	pub fn find_portal(&self, map_id: &u32, portal_idx: &u32) -> Option<&Portal> {
+		self.portals.iter().find(|portal| portal.map_id == *map_id && portal.portal_idx == *portal_idx)
+	}
+
+	// Destination of a passway, if its target portal exists.
+	pub fn get_destination(&self, passway: &Passway) -> Option<&Portal> {
+		self.find_portal(&passway.target_map_id, &passway.target_portal_idx)
+	}
+
+	// Passways leading to a portal that is not defined.
+	pub fn get_dangling_passways(&self) -> Vec<&Passway> {
+		self.passways.iter().filter(|passway| self.get_destination(passway).is_none()).collect()
+	}
+
+	// Passways of the map without a matching portal in its DMap.
+	pub fn get_passways_missing_from_dmap(&self, map_id: &u32, dmap: &DMap) -> Vec<&Passway> {
+		self.passways
+			.iter()
+			.filter(|passway| passway.map_id == *map_id)
+			.filter(|passway| !dmap.portals.iter().any(|portal| portal.id == passway.passway_idx))
+			.collect()
+	}
+
+	pub fn build_world_graph(&self) -> WorldGraph<'_> {
+		let mut edges: HashMap<u32, Vec<&Passway>> = HashMap::new();
+
+		for passway in &self.passways {
+			if self.get_destination(passway).is_some() {
+				edges.entry(passway.map_id).or_default().push(passway);
+			}
+		}
+
+		WorldGraph { edges }
+	}
+}
+
+// Map connectivity, each map leading to others through its passways.
+pub struct WorldGraph<'a> {
+	pub edges: HashMap<u32, Vec<&'a Passway>>
+}
+
+impl<'a> WorldGraph<'a> {
+	pub fn get_neighbours(&self, map_id: &u32) -> Vec<u32> {
+		match self.edges.get(map_id) {
+			Some(passways) => passways.iter().map(|passway| passway.target_map_id).collect(),
+			None           => Vec::new()
+		}
+	}
+
+	// Passways to take to go from a map to another with as few hops as possible,
+	// or None if the target cannot be reached.
+	pub fn get_shortest_route(&self, from_map_id: &u32, to_map_id: &u32) -> Option<Vec<&'a Passway>> {
+		if from_map_id == to_map_id {
+			return Some(Vec::new());
+		}
+
+		let mut previous: HashMap<u32, &'a Passway> = HashMap::new();
+		let mut queue = VecDeque::new();
+
+		queue.push_back(*from_map_id);
+
+		while let Some(map_id) = queue.pop_front() {
+			for passway in self.edges.get(&map_id).map(|passways| passways.as_slice()).unwrap_or(&[]) {
+				let target = passway.target_map_id;
+
+				if target == *from_map_id || previous.contains_key(&target) {
+					continue;
+				}
+
+				previous.insert(target, passway);
+
+				if target == *to_map_id {
+					let mut route = Vec::new();
+					let mut current = target;
+
+					while let Some(passway) = previous.get(&current) {
+						route.push(*passway);
+						current = passway.map_id;
+
+						if current == *from_map_id {
+							break;
+						}
+					}
+
+					route.reverse();
+					return Some(route);
+				}
+
+				queue.push_back(target);
+			}
+		}
+
+		None
+	}
+}
+
+mod serializer {
+	use ::portal::{Passway, Portal};
+	use ::serializer::{StringSerializableStructure, SpaceSeparatedSerializer};
+
+	impl StringSerializableStructure for Portal {
+		fn serialize(&self) -> String {
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&self.id);
+			serializer.push_value(&self.map_id);
+			serializer.push_value(&self.x);
+			serializer.push_value(&self.y);
+			serializer.push_value(&self.portal_idx);
+
+			serializer.get_buffer()
+		}
+	}
+
+	impl StringSerializableStructure for Passway {
+		fn serialize(&self) -> String {
+			let mut serializer = SpaceSeparatedSerializer::new();
+
+			serializer.push_value(&self.id);
+			serializer.push_value(&self.map_id);
+			serializer.push_value(&self.passway_idx);
+			serializer.push_value(&self.target_map_id);
+			serializer.push_value(&self.target_portal_idx);
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::*;
+	use parser::*;
+
+	impl SpaceSeparatedParseable<Portal> for Portal {
+		fn from_line(line: &String) -> Result<Portal, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 5 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let portal = Portal {
+				id:         get_value_as!(results, 0, u32, "id"),
+				map_id:     get_value_as!(results, 1, u32, "map id"),
+				x:          get_value_as!(results, 2, u16, "portal x"),
+				y:          get_value_as!(results, 3, u16, "portal y"),
+				portal_idx: get_value_as!(results, 4, u32, "portal index")
+			};
+
+			Result::Ok(portal)
+		}
+	}
+
+	impl SpaceSeparatedParseable<Passway> for Passway {
+		fn from_line(line: &String) -> Result<Passway, SpaceSeparatedParserError> {
+			let results = get_space_separated_values(line);
+
+			if results.len() < 5 {
+				return Result::Err(SpaceSeparatedParserError::MissingAtIndex(results.len()));
+			}
+
+			let passway = Passway {
+				id:                get_value_as!(results, 0, u32, "id"),
+				map_id:            get_value_as!(results, 1, u32, "map id"),
+				passway_idx:       get_value_as!(results, 2, u32, "passway index"),
+				target_map_id:     get_value_as!(results, 3, u32, "passway map id"),
+				target_portal_idx: get_value_as!(results, 4, u32, "passway map portal")
+			};
+
+			Result::Ok(passway)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Passway, Portal, PortalTable};
+
+	fn portal(map_id: u32, portal_idx: u32) -> Portal {
+		Portal { id: map_id * 10 + portal_idx, map_id, x: 50, y: 50, portal_idx }
+	}
+
+	fn passway(id: u32, map_id: u32, target_map_id: u32) -> Passway {
+		Passway { id, map_id, passway_idx: 0, target_map_id, target_portal_idx: 0 }
+	}
+
+	// 1002 -> 1000 -> 1015 -> 1011, with 1000 <-> 1002 both ways, and a passway from 1015
+	// to 1020 whose target portal is missing.
+	fn table() -> PortalTable {
+		PortalTable {
+			portals:  vec![portal(1000, 0), portal(1002, 0), portal(1011, 0), portal(1015, 0)],
+			passways: vec![
+				passway(1, 1002, 1000),
+				passway(2, 1000, 1002),
+				passway(3, 1000, 1015),
+				passway(4, 1015, 1011),
+				passway(5, 1015, 1020)
+			]
+		}
+	}
+
+	fn get_route_ids(table: &PortalTable, from: u32, to: u32) -> Option<Vec<u32>> {
+		table
+			.build_world_graph()
+			.get_shortest_route(&from, &to)
+			.map(|route| route.iter().map(|passway| passway.id).collect())
+	}
+
+	#[test]
+	fn finds_routes_over_several_hops() {
+		assert_eq!(get_route_ids(&table(), 1002, 1011), Some(vec![1, 3, 4]));
+		assert_eq!(get_route_ids(&table(), 1000, 1002), Some(vec![2]));
+	}
+
+	#[test]
+	fn finds_no_route_to_an_unreachable_map() {
+		assert_eq!(get_route_ids(&table(), 1011, 1000), None);
+	}
+
+	#[test]
+	fn finds_an_empty_route_to_the_same_map() {
+		assert_eq!(get_route_ids(&table(), 1011, 1011), Some(Vec::new()));
+	}
+
+	#[test]
+	fn skips_dangling_passways() {
+		let table = table();
+
+		assert_eq!(get_route_ids(&table, 1015, 1020), None);
+		assert_eq!(table.get_dangling_passways().iter().map(|passway| passway.id).collect::<Vec<u32>>(), vec![5]);
+	}
+}
\ No newline at end of file
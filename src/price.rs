@@ -0,0 +1,52 @@
+use item::{BaseItem, ItemQuality};
+
+// Shops buy items back for a third of their price.
+pub const SELL_PRICE_DIVIDER: u32 = 3;
+
+impl ItemQuality {
+	// Value of the quality, in percent of a normal item.
+	pub fn get_price_pct(&self) -> u32 {
+		match *self {
+			ItemQuality::Normal  => 100,
+			ItemQuality::Refined => 110,
+			ItemQuality::Unique  => 120,
+			ItemQuality::Elite   => 150,
+			ItemQuality::Super   => 200
+		}
+	}
+}
+
+impl BaseItem {
+	// Silver given back by a shop for the item, or None if it cannot be sold.
+	// Worn items lose value with their durability, composed items gain 10% per plus level.
+	pub fn get_sell_price(&self, durability: &u16, plus_lvl: &u8) -> Option<u32> {
+		if self.flags.sell_disabled {
+			return None;
+		}
+
+		let mut price = self.buy_price as u64 / SELL_PRICE_DIVIDER as u64;
+
+		if self.amount_limit > 0 {
+			let durability = (*durability).min(self.amount_limit);
+
+			price = price * durability as u64 / self.amount_limit as u64;
+		}
+
+		price = price * self.get_quality().get_price_pct() as u64 / 100;
+		price = price * (100 + *plus_lvl as u64 * 10) / 100;
+
+		Some(price.min(u32::MAX as u64) as u32)
+	}
+
+	// Tests if shops sell the item for CPs.
+	pub fn is_sold_for_cps(&self) -> bool { self.buy_cps_price > 0 }
+
+	// CPs needed to buy the item, or None if it is only sold for silver.
+	pub fn get_cps_price(&self, amount: &u16) -> Option<u32> {
+		if !self.is_sold_for_cps() {
+			return None;
+		}
+
+		Some(self.buy_cps_price as u32 * *amount as u32)
+	}
+}
\ No newline at end of file
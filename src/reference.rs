@@ -0,0 +1,10 @@
+// Row holding a reference to a client asset (effect, sound, ...).
+pub enum ReferenceSource {
+	MagicType(u32, u8),
+	Monster(String)
+}
+
+// Tests if an effect or sound field actually refers to something.
+pub fn is_reference_set(name: &str) -> bool {
+	!name.is_empty() && name != "0" && !name.eq_ignore_ascii_case("NULL")
+}
\ No newline at end of file
@@ -63,6 +63,86 @@ impl IniEntrySerializer {
 	}
 }
 
+// Records found both as ini and space separated files implement this as their ini section;
+// their space separated line is given by a private SpaceSeparated wrapper around the record.
 pub trait StringSerializableStructure {
 	fn serialize(&self) -> String;
+}
+
+// Little endian writer for binary files.
+pub struct BinarySerializer {
+	buffer: Vec<u8>
+}
+
+impl BinarySerializer {
+	pub fn new() -> BinarySerializer {
+		BinarySerializer {
+			buffer: Vec::new()
+		}
+	}
+
+	pub fn push_bytes(&mut self, bytes: &[u8]) {
+		self.buffer.extend_from_slice(bytes);
+	}
+
+	pub fn push_u8(&mut self, value: &u8) {
+		self.buffer.push(*value);
+	}
+
+	pub fn push_u16(&mut self, value: &u16) {
+		self.push_bytes(&value.to_le_bytes());
+	}
+
+	pub fn push_i16(&mut self, value: &i16) {
+		self.push_bytes(&value.to_le_bytes());
+	}
+
+	pub fn push_u32(&mut self, value: &u32) {
+		self.push_bytes(&value.to_le_bytes());
+	}
+
+	// Writes a string in a fixed size buffer, truncated or padded with zeroes.
+	pub fn push_fixed_string(&mut self, value: &str, length: usize) {
+		let mut bytes = value.as_bytes().to_vec();
+		bytes.resize(length, 0);
+
+		self.push_bytes(&bytes);
+	}
+
+	// Writes a string prefixed by its length as an u32.
+	pub fn push_sized_string(&mut self, value: &str) {
+		self.push_u32(&(value.len() as u32));
+		self.push_bytes(value.as_bytes());
+	}
+
+	pub fn get_buffer(&self) -> Vec<u8> {
+		self.buffer.clone()
+	}
+}
+
+pub trait BinarySerializableStructure {
+	fn serialize_binary(&self, serializer: &mut BinarySerializer);
+}
+
+// Serializes entries as a space separated file, prefixed by the amount header.
+pub fn serialize_space_separated_file<T>(entries: &[T]) -> String
+	where T: StringSerializableStructure {
+	let mut buffer = entries.len().to_string();
+
+	for entry in entries {
+		buffer.push('\n');
+		buffer.push_str(&entry.serialize());
+	}
+
+	buffer
+}
+
+// Serializes entries as an ini file, sections being separated by a blank line.
+pub fn serialize_ini_file<T>(entries: &[T]) -> String
+	where T: StringSerializableStructure {
+	entries
+		.iter()
+		.map(|entry| entry.serialize())
+		.collect::<Vec<String>>()
+		.join("\n\n")
 }
\ No newline at end of file
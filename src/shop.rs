@@ -0,0 +1,177 @@
+use itemtable::ItemTable;
+use parser::{split_ini_sections, IniEntryParseable, IniEntryParserError};
+use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+pub const SHOP_HEADER_SECTION: &str = "Header";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ShopMoneyType {
+	Silver,
+	Cps,
+	Unknown(u8)
+}
+
+impl ShopMoneyType {
+	pub fn from_byte(money_type: &u8) -> ShopMoneyType {
+		match *money_type {
+			0     => ShopMoneyType::Silver,
+			1     => ShopMoneyType::Cps,
+			other => ShopMoneyType::Unknown(other)
+		}
+	}
+
+	pub fn get_as_byte(&self) -> u8 {
+		match *self {
+			ShopMoneyType::Silver         => 0,
+			ShopMoneyType::Cps            => 1,
+			ShopMoneyType::Unknown(other) => other
+		}
+	}
+}
+
+// Shop of Shop.dat, stored in a [ShopN] section.
+pub struct Shop {
+	pub idx:        u32,
+	pub id:         u32,
+	pub name:       String,
+	pub shop_type:  u8,
+	pub money_type: ShopMoneyType,
+	pub items:      Vec<u32>
+}
+
+pub enum ShopIssue {
+	MissingItem(u32, u32),
+	SellDisabledItem(u32, u32),
+	NoSilverPrice(u32, u32),
+	NoCpsPrice(u32, u32)
+}
+
+pub struct ShopTable {
+	pub shops: Vec<Shop>
+}
+
+impl ShopTable {
+	// Loads every shop, the [Header] section being rebuilt on serialization.
+	pub fn from_file_string(content: &str) -> Result<ShopTable, IniEntryParserError> {
+		let mut shops = Vec::new();
+
+		for section in split_ini_sections(content) {
+			if section.starts_with(&format!("[{}]", SHOP_HEADER_SECTION)) {
+				continue;
+			}
+
+			shops.push(Shop::from_section_string(&section)?);
+		}
+
+		Result::Ok(ShopTable { shops })
+	}
+
+	pub fn serialize(&self) -> String {
+		let mut header = IniEntrySerializer::new();
+
+		header.add_section(&SHOP_HEADER_SECTION);
+		header.add_entry("Amount", &self.shops.len());
+
+		let mut buffer = header.get_buffer();
+
+		for shop in &self.shops {
+			buffer.push_str("\n\n");
+			buffer.push_str(&shop.serialize());
+		}
+
+		buffer
+	}
+
+	pub fn find(&self, id: &u32) -> Option<&Shop> {
+		self.shops.iter().find(|shop| shop.id == *id)
+	}
+
+	// Reports listed items missing from the item table, items that cannot be dealt with by
+	// shops, and items lacking a price in the shop currency. Issues hold the shop and item ids.
+	pub fn validate(&self, items: &ItemTable) -> Vec<ShopIssue> {
+		let mut issues = Vec::new();
+
+		for shop in &self.shops {
+			for item_id in &shop.items {
+				let item = match items.find(item_id) {
+					Some(item) => item,
+					None => {
+						issues.push(ShopIssue::MissingItem(shop.id, *item_id));
+						continue;
+					}
+				};
+
+				if item.flags.sell_disabled {
+					issues.push(ShopIssue::SellDisabledItem(shop.id, *item_id));
+				}
+
+				match shop.money_type {
+					ShopMoneyType::Silver if item.buy_price == 0 => issues.push(ShopIssue::NoSilverPrice(shop.id, *item_id)),
+					ShopMoneyType::Cps if !item.is_sold_for_cps() => issues.push(ShopIssue::NoCpsPrice(shop.id, *item_id)),
+					_ => {}
+				}
+			}
+		}
+
+		issues
+	}
+}
+
+mod serializer {
+	use super::Shop;
+	use serializer::{IniEntrySerializer, StringSerializableStructure};
+
+	impl StringSerializableStructure for Shop {
+		fn serialize(&self) -> String {
+			let mut serializer: IniEntrySerializer = IniEntrySerializer::new();
+
+			serializer.add_section(&format!("Shop{}", self.idx));
+			serializer.add_entry("ID", &self.id);
+			serializer.add_entry("Name", &self.name);
+			serializer.add_entry("Type", &self.shop_type);
+			serializer.add_entry("MoneyType", &self.money_type.get_as_byte());
+			serializer.add_entry("ItemAmount", &self.items.len());
+
+			for (idx, item_id) in self.items.iter().enumerate() {
+				serializer.add_entry(&format!("Item{}", idx), item_id);
+			}
+
+			serializer.get_buffer()
+		}
+	}
+}
+
+mod parser {
+	use super::{Shop, ShopMoneyType};
+	use parser::{IniEntryParser, IniEntryParserError, IniEntryParseable};
+
+	impl IniEntryParseable<Shop> for Shop {
+		fn from_section_string(section: &String) -> Result<Shop, IniEntryParserError> {
+			let parser = IniEntryParser::new_from_str(section.clone())?;
+			let section_name = parser.get_section();
+
+			let idx = match section_name.trim_start_matches("Shop").parse::<u32>() {
+				Result::Ok(idx) => idx,
+				Result::Err(_)  => return Result::Err(IniEntryParserError::InvalidCast(section_name))
+			};
+
+			let item_count: usize = parser.get_entry_as("ItemAmount")?;
+			let mut items = Vec::new();
+
+			for item_idx in 0..item_count {
+				items.push(parser.get_entry_as(&format!("Item{}", item_idx))?);
+			}
+
+			let shop = Shop {
+				idx,
+				id:         parser.get_entry_as("ID")?,
+				name:       parser.get_entry("Name")?,
+				shop_type:  parser.get_entry_as("Type")?,
+				money_type: ShopMoneyType::from_byte(&parser.get_entry_as("MoneyType")?),
+				items
+			};
+
+			Result::Ok(shop)
+		}
+	}
+}
\ No newline at end of file
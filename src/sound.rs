@@ -0,0 +1,222 @@
+use magictypetable::MagicTypeTable;
+use monstertable::MonsterTable;
+use parser::IniEntryParserError;
+use reference::{is_reference_set, ReferenceSource};
+
+// Sound of the index, with the section it was listed under; None for the entries found
+// before any section header.
+pub struct SoundEntry {
+	pub section: Option<String>,
+	pub name:    String,
+	pub path:    String
+}
+
+pub struct MissingSound {
+	pub source:    ReferenceSource,
+	pub field:     &'static str,
+	pub reference: String
+}
+
+// Client sound index: named sounds from the sound ini, and optionally the list of sound
+// files actually shipped, so that dangling entries can be spotted.
+pub struct SoundIndex {
+	pub sounds: Vec<SoundEntry>,
+	pub assets: Option<Vec<String>>
+}
+
+// Paths are compared without case, whatever the separator used.
+fn normalize_path(path: &str) -> String {
+	path.replace('\\', "/").to_lowercase()
+}
+
+impl SoundIndex {
+	// Entries are read line by line rather than through the ini parser, so that sections and
+	// the order of the file are kept when writing it back.
+	pub fn from_ini_string(content: &str) -> Result<SoundIndex, IniEntryParserError> {
+		let mut sounds = Vec::new();
+		let mut section: Option<String> = None;
+
+		for line in content.lines() {
+			let line = line.trim();
+
+			if line.is_empty() || line.starts_with(';') {
+				continue;
+			}
+
+			if line.starts_with('[') {
+				section = Some(String::from(line.trim_start_matches('[').trim_end_matches(']')));
+				continue;
+			}
+
+			let (name, path) = match line.find('=') {
+				Some(idx) => (&line[..idx], &line[idx + 1..]),
+				None      => return Result::Err(IniEntryParserError::MissingKey(String::from(line)))
+			};
+
+			sounds.push(SoundEntry {
+				section: section.clone(),
+				name:    String::from(name.trim()),
+				path:    String::from(path.trim())
+			});
+		}
+
+		Result::Ok(SoundIndex { sounds, assets: None })
+	}
+
+	// Loads the list of shipped sound files, one path per line.
+	pub fn set_asset_list(&mut self, content: &str) {
+		self.assets = Some(content
+			.lines()
+			.map(|line| line.trim())
+			.filter(|line| !line.is_empty())
+			.map(normalize_path)
+			.collect());
+	}
+
+	// Writes the entries in their order, each run of entries under the header of its section.
+	pub fn serialize(&self) -> String {
+		let mut lines: Vec<String> = Vec::new();
+		let mut current: Option<&String> = None;
+
+		for sound in &self.sounds {
+			let section = sound.section.as_ref();
+
+			if section != current {
+				if !lines.is_empty() {
+					lines.push(String::new());
+				}
+
+				if let Some(name) = section {
+					lines.push(format!("[{}]", name));
+				}
+
+				current = section;
+			}
+
+			lines.push(format!("{}={}", sound.name, sound.path));
+		}
+
+		lines.join("\n")
+	}
+
+	fn is_asset_present(&self, path: &str) -> bool {
+		match self.assets {
+			Some(ref assets) => assets.contains(&normalize_path(path)),
+			None             => true
+		}
+	}
+
+	// References either name an entry of the index or directly give a file path.
+	pub fn resolve(&self, reference: &str) -> Option<String> {
+		if !is_reference_set(reference) {
+			return None;
+		}
+
+		if let Some(sound) = self.sounds.iter().find(|sound| sound.name == reference) {
+			return Some(sound.path.clone());
+		}
+
+		let normalized = normalize_path(reference);
+
+		if let Some(sound) = self.sounds.iter().find(|sound| normalize_path(&sound.path) == normalized) {
+			return Some(sound.path.clone());
+		}
+
+		match self.assets {
+			Some(ref assets) if assets.contains(&normalized) => Some(String::from(reference)),
+			_                                                => None
+		}
+	}
+
+	fn get_references(magic_types: &MagicTypeTable, monsters: &MonsterTable) -> Vec<(ReferenceSource, &'static str, String)> {
+		let mut references = Vec::new();
+
+		for entry in &magic_types.entries {
+			let fields = [
+				("intone_sfx", &entry.effects.intone_sfx),
+				("sender_sfx", &entry.effects.sender_sfx),
+				("target_sfx", &entry.effects.target_sfx)
+			];
+
+			for &(field, reference) in fields.iter() {
+				if is_reference_set(reference) {
+					references.push((ReferenceSource::MagicType(entry.id, entry.skill_lvl), field, reference.clone()));
+				}
+			}
+		}
+
+		for monster in &monsters.monsters {
+			if is_reference_set(&monster.born_sound) {
+				references.push((ReferenceSource::Monster(monster.name.clone()), "born_sound", monster.born_sound.clone()));
+			}
+		}
+
+		references
+	}
+
+	// Sound references of magictype rows and monsters that resolve to no existing file.
+	pub fn get_missing_sounds(&self, magic_types: &MagicTypeTable, monsters: &MonsterTable) -> Vec<MissingSound> {
+		SoundIndex::get_references(magic_types, monsters)
+			.into_iter()
+			.filter(|(_, _, reference)| match self.resolve(reference) {
+				Some(path) => !self.is_asset_present(&path),
+				None       => true
+			})
+			.map(|(source, field, reference)| MissingSound { source, field, reference })
+			.collect()
+	}
+
+	// Index entries that no magictype row or monster refers to.
+	pub fn get_unused_sounds(&self, magic_types: &MagicTypeTable, monsters: &MonsterTable) -> Vec<&SoundEntry> {
+		let used: Vec<String> = SoundIndex::get_references(magic_types, monsters)
+			.iter()
+			.filter_map(|(_, _, reference)| self.resolve(reference))
+			.map(|path| normalize_path(&path))
+			.collect();
+
+		self.sounds
+			.iter()
+			.filter(|sound| !used.contains(&normalize_path(&sound.path)))
+			.collect()
+	}
+
+	// Shipped sound files that are not listed in the index.
+	pub fn get_unindexed_assets(&self) -> Vec<&String> {
+		let indexed: Vec<String> = self.sounds.iter().map(|sound| normalize_path(&sound.path)).collect();
+
+		match self.assets {
+			Some(ref assets) => assets.iter().filter(|asset| !indexed.contains(asset)).collect(),
+			None             => Vec::new()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SoundIndex;
+
+	fn load(content: &str) -> SoundIndex {
+		match SoundIndex::from_ini_string(content) {
+			Result::Ok(index) => index,
+			Result::Err(_)    => panic!("failed to parse {}", content)
+		}
+	}
+
+	#[test]
+	fn reads_entries_without_section() {
+		let index = load("hit=sound/hit.wav\nmiss=sound/miss.wav");
+
+		assert_eq!(index.sounds.len(), 2);
+		assert!(index.sounds[0].section.is_none());
+	}
+
+	#[test]
+	fn round_trips_every_section_in_order() {
+		let content = "general=sound/general.wav\n\n[Sound]\nzap=sound/zap.wav\nbang=sound/bang.wav\n\n[Music]\ntheme=sound/theme.mp3";
+		let index = load(content);
+
+		assert_eq!(index.sounds.len(), 4);
+		assert_eq!(index.resolve("theme"), Some(String::from("sound/theme.mp3")));
+		assert_eq!(index.serialize(), content);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use item::BaseItem;
+use magictype::BaseMagicTypeEntry;
+use parser::IniEntryParserError;
+
+pub const STRRES_SECTION: &str = "StrRes";
+
+pub struct StringResource {
+	pub id:   u32,
+	pub text: String
+}
+
+// Client StrRes.ini table. Texts are read raw up to the end of the line, as they commonly
+// hold characters the ini parser would otherwise interpret.
+pub struct StringResourceTable {
+	pub resources: Vec<StringResource>
+}
+
+impl StringResourceTable {
+	pub fn from_file_string(content: &str) -> Result<StringResourceTable, IniEntryParserError> {
+		let mut resources = Vec::new();
+
+		for line in content.lines() {
+			let line = line.trim_start();
+
+			if line.is_empty() || line.starts_with('[') || line.starts_with(';') {
+				continue;
+			}
+
+			let (id, text) = match line.find('=') {
+				Some(idx) => (&line[..idx], &line[idx + 1..]),
+				None      => return Result::Err(IniEntryParserError::MissingKey(String::from(line)))
+			};
+
+			resources.push(StringResource {
+				id:   match id.trim().parse::<u32>() {
+					Result::Ok(id) => id,
+					Result::Err(_) => return Result::Err(IniEntryParserError::InvalidCast(String::from(id)))
+				},
+				text: String::from(text.trim_end_matches('\r'))
+			});
+		}
+
+		Result::Ok(StringResourceTable { resources })
+	}
+
+	pub fn serialize(&self) -> String {
+		let mut buffer = format!("[{}]", STRRES_SECTION);
+
+		for resource in &self.resources {
+			buffer.push_str(&format!("\n{}={}", resource.id, resource.text));
+		}
+
+		buffer
+	}
+
+	pub fn get(&self, id: &u32) -> Option<&str> {
+		self.resources
+			.iter()
+			.find(|resource| resource.id == *id)
+			.map(|resource| resource.text.as_str())
+	}
+}
+
+// Space separated files cannot hold spaces, so texts store them as '~'.
+pub fn get_display_text(text: &str) -> String {
+	text.replace('~', " ")
+}
+
+pub struct LocalizedTable {
+	pub language: String,
+	pub table:    StringResourceTable
+}
+
+// Resolves texts against the string resource table of the chosen language. A text made of
+// a numeric id only refers to a string resource; anything else is shown as is.
+pub struct Localization {
+	pub language: String,
+	pub tables:   Vec<LocalizedTable>
+}
+
+impl Localization {
+	pub fn new(language: &str) -> Localization {
+		Localization {
+			language: String::from(language),
+			tables:   Vec::new()
+		}
+	}
+
+	pub fn add_language(&mut self, language: &str, table: StringResourceTable) {
+		self.tables.retain(|localized| localized.language != language);
+		self.tables.push(LocalizedTable { language: String::from(language), table });
+	}
+
+	pub fn set_language(&mut self, language: &str) {
+		self.language = String::from(language);
+	}
+
+	pub fn get_table(&self) -> Option<&StringResourceTable> {
+		self.tables
+			.iter()
+			.find(|localized| localized.language == self.language)
+			.map(|localized| &localized.table)
+	}
+
+	pub fn resolve(&self, text: &str) -> String {
+		if let Result::Ok(id) = text.parse::<u32>() {
+			if let Some(resource) = self.get_table().and_then(|table| table.get(&id)) {
+				return String::from(resource);
+			}
+		}
+
+		get_display_text(text)
+	}
+
+	pub fn get_item_description(&self, item: &BaseItem) -> String {
+		self.resolve(&item.description)
+	}
+
+	pub fn get_magic_description(&self, entry: &BaseMagicTypeEntry) -> String {
+		self.resolve(&entry.desc)
+	}
+}
\ No newline at end of file
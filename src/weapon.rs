@@ -0,0 +1,111 @@
+use item::BaseItem;
+
+pub const DEFAULT_ATK_INTERVAL_MS: u32 = 1000;
+pub const MIN_BOW_RANGE: u8 = 2;
+pub const MAX_MELEE_RANGE: u8 = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum WeaponCategory {
+	OneHanded,
+	TwoHanded,
+	Bow,
+	Shield,
+	NotAWeapon
+}
+
+impl WeaponCategory {
+	// Categories are given by the three first digits of the item id:
+	// 4xx for one-handers, 5xx for two-handers, 500 being the bows, and 900 for shields.
+	pub fn from_item_id(id: &u32) -> WeaponCategory {
+		match *id / 1000 {
+			500       => WeaponCategory::Bow,
+			400..=499 => WeaponCategory::OneHanded,
+			501..=599 => WeaponCategory::TwoHanded,
+			900       => WeaponCategory::Shield,
+			_         => WeaponCategory::NotAWeapon
+		}
+	}
+
+	pub fn is_melee(&self) -> bool {
+		*self == WeaponCategory::OneHanded || *self == WeaponCategory::TwoHanded
+	}
+
+	// Reach used when the item does not set one.
+	pub fn get_default_range(&self) -> u8 {
+		match *self {
+			WeaponCategory::TwoHanded => 2,
+			WeaponCategory::Bow       => 10,
+			_                         => 1
+		}
+	}
+}
+
+pub enum WeaponIssue {
+	NotAWeapon,
+	MissingAttackSpeed,
+	BowRangeTooShort(u8),
+	MeleeRangeTooLong(u8),
+	ShieldWithRange(u8)
+}
+
+impl BaseItem {
+	pub fn get_weapon_category(&self) -> WeaponCategory { WeaponCategory::from_item_id(&self.id) }
+
+	// Delay between two attacks, in milliseconds.
+	pub fn get_atk_interval_ms(&self) -> Option<u32> {
+		match self.get_weapon_category() {
+			WeaponCategory::NotAWeapon | WeaponCategory::Shield => None,
+			_ if self.combat_stats.atk_speed == 0 => Some(DEFAULT_ATK_INTERVAL_MS),
+			_ => Some(self.combat_stats.atk_speed as u32)
+		}
+	}
+
+	// Distance, in map cells, at which the weapon can hit.
+	pub fn get_reach(&self) -> Option<u8> {
+		let category = self.get_weapon_category();
+
+		match category {
+			WeaponCategory::NotAWeapon | WeaponCategory::Shield => None,
+			_ if self.combat_stats.atk_range == 0 => Some(category.get_default_range()),
+			_ => Some(self.combat_stats.atk_range)
+		}
+	}
+
+	// Reports every inconsistency between the weapon category and its speed and range.
+	pub fn validate_weapon(&self) -> Vec<WeaponIssue> {
+		let mut issues = Vec::new();
+		let category = self.get_weapon_category();
+		let range = self.combat_stats.atk_range;
+
+		match category {
+			WeaponCategory::NotAWeapon => {
+				issues.push(WeaponIssue::NotAWeapon);
+				return issues;
+			},
+
+			WeaponCategory::Shield => {
+				if range > 0 {
+					issues.push(WeaponIssue::ShieldWithRange(range));
+				}
+
+				return issues;
+			},
+
+			WeaponCategory::Bow if range > 0 && range < MIN_BOW_RANGE => {
+				issues.push(WeaponIssue::BowRangeTooShort(range));
+			},
+
+			_ if category.is_melee() && range > MAX_MELEE_RANGE => {
+				issues.push(WeaponIssue::MeleeRangeTooLong(range));
+			},
+
+			_ => {}
+		}
+
+		if self.combat_stats.atk_speed == 0 {
+			issues.push(WeaponIssue::MissingAttackSpeed);
+		}
+
+		issues
+	}
+}
\ No newline at end of file